@@ -0,0 +1,56 @@
+//! Console logger throughput under thread contention
+//!
+//! Compares the locked `ConsoleLogger` with `ShardedConsoleLogger`, both
+//! writing to `io::sink()` so only the logging path is measured.
+//!
+//! ```text
+//! cargo bench --bench console_contention
+//! ```
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tyl_logging::{ConsoleLogger, LogLevel, LogRecord, Logger, ShardedConsoleLogger};
+
+const THREADS: usize = 32;
+const RECORDS_PER_THREAD: usize = 20_000;
+
+fn run(logger: Arc<dyn Logger + Send + Sync>) -> Duration {
+    let start = Instant::now();
+    let handles: Vec<_> = (0..THREADS)
+        .map(|thread| {
+            let logger = logger.clone();
+            std::thread::spawn(move || {
+                let record = LogRecord::new(LogLevel::Info, format!("request handled by {thread}"));
+                for _ in 0..RECORDS_PER_THREAD {
+                    logger.log(&record);
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    logger.flush();
+    start.elapsed()
+}
+
+fn report(name: &str, elapsed: Duration) {
+    let records = (THREADS * RECORDS_PER_THREAD) as f64;
+    println!(
+        "{name:<24} {:>8.1} ms  {:>12.0} records/s",
+        elapsed.as_secs_f64() * 1000.0,
+        records / elapsed.as_secs_f64()
+    );
+}
+
+fn main() {
+    println!("{THREADS} threads x {RECORDS_PER_THREAD} records");
+    report(
+        "ConsoleLogger",
+        run(Arc::new(ConsoleLogger::with_writer(std::io::sink()))),
+    );
+    report(
+        "ShardedConsoleLogger",
+        run(Arc::new(ShardedConsoleLogger::with_writer(std::io::sink()))),
+    );
+}
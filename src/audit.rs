@@ -0,0 +1,156 @@
+//! Audit-trail records
+//!
+//! Compliance audit logs must always say who did what to which resource and
+//! how it ended. [`AuditRecord`] tracks those four fields in its type, so a
+//! record missing one of them does not compile.
+
+use crate::record::{LogLevel, LogRecord};
+use serde_json::Value;
+
+/// Field marking a record as part of the audit trail
+pub const AUDIT_MARKER_FIELD: &str = "_audit";
+
+/// Builder state: a required field not set yet
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Missing;
+
+/// Builder state: a required field that has been set
+#[derive(Debug, Clone)]
+pub struct Set(String);
+
+/// Builder for an `Info` audit record with `actor`, `action`, `resource` and `outcome`
+///
+/// [`build`](AuditRecord::build) only exists once all four are set. The
+/// record's message is the action, and it carries `"_audit": true`.
+///
+/// ```rust
+/// use tyl_logging::{AuditRecord, JsonLogger, Logger};
+///
+/// let record = AuditRecord::new()
+///     .actor("user-42")
+///     .action("invoice.delete")
+///     .resource("invoice-7")
+///     .outcome("denied")
+///     .field("reason", serde_json::json!("not owner"))
+///     .build();
+/// JsonLogger::new().log(&record);
+/// ```
+///
+/// Leaving out a required field is a compile error:
+///
+/// ```rust,compile_fail
+/// use tyl_logging::AuditRecord;
+///
+/// let record = AuditRecord::new()
+///     .actor("user-42")
+///     .action("invoice.delete")
+///     .resource("invoice-7")
+///     .build();
+/// ```
+#[derive(Debug, Clone)]
+#[must_use = "an audit record is only logged once built and passed to a logger"]
+pub struct AuditRecord<Actor = Missing, Action = Missing, Resource = Missing, Outcome = Missing> {
+    actor: Actor,
+    action: Action,
+    resource: Resource,
+    outcome: Outcome,
+    fields: Vec<(String, Value)>,
+}
+
+impl AuditRecord {
+    /// Start an audit record with no fields set
+    pub fn new() -> Self {
+        Self {
+            actor: Missing,
+            action: Missing,
+            resource: Missing,
+            outcome: Missing,
+            fields: Vec::new(),
+        }
+    }
+}
+
+impl Default for AuditRecord {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Actor, Action, Resource, Outcome> AuditRecord<Actor, Action, Resource, Outcome> {
+    /// Add an extra field beyond the required four
+    pub fn field(mut self, key: impl Into<String>, value: Value) -> Self {
+        self.fields.push((key.into(), value));
+        self
+    }
+}
+
+impl<Action, Resource, Outcome> AuditRecord<Missing, Action, Resource, Outcome> {
+    /// Set who performed the action
+    pub fn actor(self, actor: impl Into<String>) -> AuditRecord<Set, Action, Resource, Outcome> {
+        AuditRecord {
+            actor: Set(actor.into()),
+            action: self.action,
+            resource: self.resource,
+            outcome: self.outcome,
+            fields: self.fields,
+        }
+    }
+}
+
+impl<Actor, Resource, Outcome> AuditRecord<Actor, Missing, Resource, Outcome> {
+    /// Set what was done
+    pub fn action(self, action: impl Into<String>) -> AuditRecord<Actor, Set, Resource, Outcome> {
+        AuditRecord {
+            actor: self.actor,
+            action: Set(action.into()),
+            resource: self.resource,
+            outcome: self.outcome,
+            fields: self.fields,
+        }
+    }
+}
+
+impl<Actor, Action, Outcome> AuditRecord<Actor, Action, Missing, Outcome> {
+    /// Set what it was done to
+    pub fn resource(self, resource: impl Into<String>) -> AuditRecord<Actor, Action, Set, Outcome> {
+        AuditRecord {
+            actor: self.actor,
+            action: self.action,
+            resource: Set(resource.into()),
+            outcome: self.outcome,
+            fields: self.fields,
+        }
+    }
+}
+
+impl<Actor, Action, Resource> AuditRecord<Actor, Action, Resource, Missing> {
+    /// Set how it ended, e.g. `"success"` or `"denied"`
+    pub fn outcome(self, outcome: impl Into<String>) -> AuditRecord<Actor, Action, Resource, Set> {
+        AuditRecord {
+            actor: self.actor,
+            action: self.action,
+            resource: self.resource,
+            outcome: Set(outcome.into()),
+            fields: self.fields,
+        }
+    }
+}
+
+impl AuditRecord<Set, Set, Set, Set> {
+    /// Produce the `Info` log record
+    ///
+    /// The required fields are added last, so an extra field with the same
+    /// name cannot replace them.
+    pub fn build(self) -> LogRecord {
+        let mut record = LogRecord::new(LogLevel::Info, self.action.0.clone());
+        for (key, value) in self.fields {
+            record.add_field(key, value);
+        }
+        record.add_field("actor", Value::String(self.actor.0));
+        record.add_field("action", Value::String(self.action.0));
+        record.add_field("resource", Value::String(self.resource.0));
+        record.add_field("outcome", Value::String(self.outcome.0));
+        record.add_field(AUDIT_MARKER_FIELD, Value::Bool(true));
+        record
+    }
+}
@@ -15,6 +15,15 @@ pub enum Environment {
 }
 
 impl Environment {
+    /// Get the lowercase name used in environment variables and output
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Environment::Development => "development",
+            Environment::Production => "production",
+            Environment::Test => "test",
+        }
+    }
+
     pub fn from_env() -> Self {
         match std::env::var("ENVIRONMENT")
             .unwrap_or_else(|_| "development".to_string())
@@ -28,12 +37,216 @@ impl Environment {
     }
 }
 
+/// Output format for config-built loggers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    Console,
+    Json,
+    Logfmt,
+}
+
+impl LogFormat {
+    /// Get the lowercase name accepted by `LOG_FORMAT`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LogFormat::Console => "console",
+            LogFormat::Json => "json",
+            LogFormat::Logfmt => "logfmt",
+        }
+    }
+
+    /// Default format for an environment: console in development, JSON elsewhere
+    pub fn default_for(environment: &Environment) -> Self {
+        match environment {
+            Environment::Development => LogFormat::Console,
+            _ => LogFormat::Json,
+        }
+    }
+}
+
+impl std::str::FromStr for LogFormat {
+    type Err = TylError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "console" => Ok(LogFormat::Console),
+            "json" => Ok(LogFormat::Json),
+            "logfmt" => Ok(LogFormat::Logfmt),
+            _ => Err(TylError::configuration(format!(
+                "invalid log format: {}",
+                s
+            ))),
+        }
+    }
+}
+
+/// Record field naming the module or subsystem a record comes from
+///
+/// [`LevelFilter`] overrides are matched against this field's string value.
+pub const TARGET_FIELD: &str = "target";
+
+/// A default level plus per-target overrides, parsed from a directive string
+///
+/// Directives are comma-separated: a bare level sets the default and
+/// `target=level` overrides it for `target` and everything under it
+/// (`db` also covers `db::pool`). Deserializes from the directive string,
+/// so it can sit directly in a serde-derived config:
+///
+/// ```rust
+/// use tyl_logging::{LevelFilter, LogLevel};
+///
+/// let filter: LevelFilter = "info,db=debug".parse().unwrap();
+/// assert_eq!(filter.level_for("db::pool"), LogLevel::Debug);
+/// assert_eq!(filter.level_for("http"), LogLevel::Info);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LevelFilter {
+    default: crate::record::LogLevel,
+    overrides: Vec<(String, crate::record::LogLevel)>,
+}
+
+impl LevelFilter {
+    /// Create a filter with `default` and no overrides
+    pub fn new(default: crate::record::LogLevel) -> Self {
+        Self {
+            default,
+            overrides: Vec::new(),
+        }
+    }
+
+    /// Use `level` for `target` and everything under it
+    pub fn with_override(
+        mut self,
+        target: impl Into<String>,
+        level: crate::record::LogLevel,
+    ) -> Self {
+        let target = target.into();
+        self.overrides.retain(|(existing, _)| *existing != target);
+        self.overrides.push((target, level));
+        self
+    }
+
+    /// Get the level for targets without an override
+    pub fn default_level(&self) -> crate::record::LogLevel {
+        self.default
+    }
+
+    /// Get the overrides in directive order
+    pub fn overrides(&self) -> &[(String, crate::record::LogLevel)] {
+        &self.overrides
+    }
+
+    /// Whether `record` meets the level for its [`TARGET_FIELD`]
+    ///
+    /// Records without a string `target` field are held to the default level.
+    pub fn allows(&self, record: &crate::record::LogRecord) -> bool {
+        let level = match record
+            .fields()
+            .get(TARGET_FIELD)
+            .and_then(serde_json::Value::as_str)
+        {
+            Some(target) => self.level_for(target),
+            None => self.default,
+        };
+        record.level() >= level
+    }
+
+    /// Level in effect for `target`: its most specific override, or the default
+    pub fn level_for(&self, target: &str) -> crate::record::LogLevel {
+        self.overrides
+            .iter()
+            .filter(|(prefix, _)| {
+                target
+                    .strip_prefix(prefix.as_str())
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+            })
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map_or(self.default, |(_, level)| *level)
+    }
+}
+
+impl Default for LevelFilter {
+    fn default() -> Self {
+        Self::new(crate::record::LogLevel::Info)
+    }
+}
+
+impl std::str::FromStr for LevelFilter {
+    type Err = TylError;
+
+    /// Parse `level,target=level,...`; the default is `Info` if no bare level is given
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid =
+            |segment: &str| TylError::configuration(format!("invalid level directive: {segment}"));
+        let mut filter = LevelFilter::default();
+        for segment in s.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+            match segment.split_once('=') {
+                None => filter.default = segment.parse().map_err(|_| invalid(segment))?,
+                Some((target, level)) => {
+                    let target = target.trim();
+                    if target.is_empty() {
+                        return Err(invalid(segment));
+                    }
+                    let level = level.trim().parse().map_err(|_| invalid(segment))?;
+                    filter = filter.with_override(target, level);
+                }
+            }
+        }
+        Ok(filter)
+    }
+}
+
+impl std::fmt::Display for LevelFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            crate::utils::format_level(self.default).to_lowercase()
+        )?;
+        for (target, level) in &self.overrides {
+            write!(
+                f,
+                ",{}={}",
+                target,
+                crate::utils::format_level(*level).to_lowercase()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl serde::Serialize for LevelFilter {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for LevelFilter {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let directive = String::deserialize(deserializer)?;
+        directive.parse().map_err(serde::de::Error::custom)
+    }
+}
+
 /// Configuration for logging setup with TYL config integration
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct LoggingConfig {
     pub service_name: String,
     pub level: crate::record::LogLevel,
     pub environment: Environment,
+    /// Attach a generated request ID to records lacking one; `None` follows the environment
+    #[serde(default)]
+    pub auto_request_id: Option<bool>,
+    /// Output format; `None` follows the environment
+    #[serde(default)]
+    pub format: Option<LogFormat>,
+    /// File that additionally receives every `Error` record
+    #[serde(default)]
+    pub error_sink_path: Option<String>,
+    /// Per-target levels applied instead of `level` when set
+    #[serde(default)]
+    pub level_filter: Option<LevelFilter>,
 }
 
 impl LoggingConfig {
@@ -42,6 +255,10 @@ impl LoggingConfig {
             service_name: service_name.into(),
             level: crate::record::LogLevel::Info,
             environment: Environment::from_env(),
+            auto_request_id: None,
+            format: None,
+            error_sink_path: None,
+            level_filter: None,
         }
     }
 
@@ -55,6 +272,27 @@ impl LoggingConfig {
         self
     }
 
+    pub fn with_auto_request_id(mut self, enabled: bool) -> Self {
+        self.auto_request_id = Some(enabled);
+        self
+    }
+
+    pub fn with_format(mut self, format: LogFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    pub fn with_error_sink_path(mut self, path: impl Into<String>) -> Self {
+        self.error_sink_path = Some(path.into());
+        self
+    }
+
+    /// Filter records by their `target` field instead of by `level` alone
+    pub fn with_level_filter(mut self, filter: LevelFilter) -> Self {
+        self.level_filter = Some(filter);
+        self
+    }
+
     pub fn service_name(&self) -> &str {
         &self.service_name
     }
@@ -66,6 +304,45 @@ impl LoggingConfig {
     pub fn environment(&self) -> Environment {
         self.environment.clone()
     }
+
+    pub fn error_sink_path(&self) -> Option<&str> {
+        self.error_sink_path.as_deref()
+    }
+
+    pub fn level_filter(&self) -> Option<&LevelFilter> {
+        self.level_filter.as_ref()
+    }
+
+    /// Output format, falling back to [`LogFormat::default_for`] the environment
+    pub fn format(&self) -> LogFormat {
+        self.format
+            .unwrap_or_else(|| LogFormat::default_for(&self.environment))
+    }
+
+    /// Describe the effective settings as a JSON object
+    ///
+    /// Defaults derived from the environment are resolved, so the result shows
+    /// what a logger built from this config actually does.
+    pub fn describe(&self) -> serde_json::Value {
+        serde_json::json!({
+            "service_name": self.service_name,
+            "level": crate::utils::format_level(self.level),
+            "environment": self.environment.as_str(),
+            "format": self.format().as_str(),
+            "auto_request_id": self.auto_request_id(),
+            "error_sink_path": self.error_sink_path,
+            "level_filter": self.level_filter.as_ref().map(ToString::to_string),
+        })
+    }
+
+    /// Whether records without a request ID get one generated
+    ///
+    /// Defaults to `true` in `Development` and `false` elsewhere unless set
+    /// explicitly with [`LoggingConfig::with_auto_request_id`].
+    pub fn auto_request_id(&self) -> bool {
+        self.auto_request_id
+            .unwrap_or(self.environment == Environment::Development)
+    }
 }
 
 impl ConfigPlugin for LoggingConfig {
@@ -81,11 +358,35 @@ impl ConfigPlugin for LoggingConfig {
         if self.service_name.is_empty() {
             return Err(TylError::validation("service_name", "cannot be empty"));
         }
+        if let Some(path) = &self.error_sink_path {
+            let path = std::path::Path::new(path);
+            if path.as_os_str().is_empty() {
+                return Err(TylError::validation("error_sink_path", "cannot be empty"));
+            }
+            if path.is_dir() {
+                return Err(TylError::validation(
+                    "error_sink_path",
+                    "must be a file, not a directory",
+                ));
+            }
+            if let Some(parent) = path.parent() {
+                if !parent.as_os_str().is_empty() && !parent.is_dir() {
+                    return Err(TylError::validation(
+                        "error_sink_path",
+                        "parent directory does not exist",
+                    ));
+                }
+            }
+        }
         Ok(())
     }
 
+    /// Return a copy of this config with environment overrides applied
+    ///
+    /// Settings not present in the environment, such as a service name
+    /// chosen with [`LoggingConfig::new`], are kept.
     fn load_from_env(&self) -> ConfigResult<Self> {
-        let mut config = Self::new("app");
+        let mut config = self.clone();
         config.merge_env()?;
         Ok(config)
     }
@@ -95,19 +396,7 @@ impl ConfigPlugin for LoggingConfig {
         if let Ok(level_str) =
             std::env::var("TYL_LOG_LEVEL").or_else(|_| std::env::var("LOG_LEVEL"))
         {
-            match level_str.to_uppercase().as_str() {
-                "TRACE" => self.level = crate::record::LogLevel::Trace,
-                "DEBUG" => self.level = crate::record::LogLevel::Debug,
-                "INFO" => self.level = crate::record::LogLevel::Info,
-                "WARN" | "WARNING" => self.level = crate::record::LogLevel::Warn,
-                "ERROR" => self.level = crate::record::LogLevel::Error,
-                _ => {
-                    return Err(TylError::configuration(format!(
-                        "invalid log level: {}",
-                        level_str
-                    )))
-                }
-            }
+            self.level = level_str.parse()?;
         }
 
         // TYL_SERVICE_NAME or SERVICE_NAME
@@ -134,6 +423,13 @@ impl ConfigPlugin for LoggingConfig {
             }
         }
 
+        // TYL_LOG_FORMAT or LOG_FORMAT
+        if let Ok(format_str) =
+            std::env::var("TYL_LOG_FORMAT").or_else(|_| std::env::var("LOG_FORMAT"))
+        {
+            self.format = Some(format_str.parse()?);
+        }
+
         Ok(())
     }
 }
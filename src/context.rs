@@ -0,0 +1,178 @@
+//! Thread-local logging context
+//!
+//! A [`LogContext`] holds a request ID and fields that apply to everything
+//! logged while it is entered on the current thread. Loggers wrapped in
+//! [`ContextLogger`](crate::loggers::ContextLogger) merge it into each record.
+//!
+//! Thread-locals don't follow work onto other threads or async tasks, so
+//! capture the context before spawning and enter it inside the new task:
+//!
+//! ```rust
+//! use tyl_logging::{capture_context, LogContext};
+//!
+//! let _guard = LogContext::new().with_request_id("req-42").enter();
+//!
+//! let context = capture_context();
+//! std::thread::spawn(move || {
+//!     let _guard = context.enter();
+//!     // records logged here carry request_id "req-42"
+//! })
+//! .join()
+//! .unwrap();
+//! ```
+//!
+//! With Tokio, enter the captured context inside the spawned future; keep the
+//! guard out of `.await` points, as the task may resume on another thread:
+//!
+//! ```rust,ignore
+//! let context = capture_context();
+//! tokio::spawn(async move {
+//!     {
+//!         let _guard = context.enter();
+//!         logger.log(&record);
+//!     }
+//!     do_io().await;
+//! });
+//! ```
+//!
+//! Request middleware can fill the context declaratively with
+//! [`ContextExtractor`]s and [`with_extracted_context`].
+
+use serde_json::Value;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+thread_local! {
+    static CURRENT: RefCell<LogContext> = RefCell::new(LogContext::default());
+}
+
+/// Request ID and fields applied to records logged on the current thread
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LogContext {
+    request_id: Option<String>,
+    fields: HashMap<String, Value>,
+}
+
+impl LogContext {
+    /// Create an empty context
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the request ID
+    pub fn with_request_id(mut self, request_id: impl Into<String>) -> Self {
+        self.request_id = Some(request_id.into());
+        self
+    }
+
+    /// Add a field
+    pub fn with_field(mut self, key: impl Into<String>, value: Value) -> Self {
+        self.fields.insert(key.into(), value);
+        self
+    }
+
+    /// Get the request ID if present
+    pub fn request_id(&self) -> Option<&str> {
+        self.request_id.as_deref()
+    }
+
+    /// Get the context fields
+    pub fn fields(&self) -> &HashMap<String, Value> {
+        &self.fields
+    }
+
+    /// Check whether the context carries nothing
+    pub fn is_empty(&self) -> bool {
+        self.request_id.is_none() && self.fields.is_empty()
+    }
+
+    /// Make this the current thread's context until the guard is dropped
+    ///
+    /// The previous context is restored when the guard drops, so scopes nest.
+    pub fn enter(&self) -> ContextGuard {
+        let previous = CURRENT.with(|current| current.replace(self.clone()));
+        ContextGuard {
+            previous: Some(previous),
+            _not_send: PhantomData,
+        }
+    }
+}
+
+/// Snapshot the current thread's context for use on another thread or task
+pub fn capture_context() -> LogContext {
+    CURRENT.with(|current| current.borrow().clone())
+}
+
+/// Restores the previous context when dropped
+///
+/// Guards are tied to the thread that created them and cannot be sent.
+#[must_use = "the context is exited as soon as the guard is dropped"]
+pub struct ContextGuard {
+    previous: Option<LogContext>,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for ContextGuard {
+    fn drop(&mut self) {
+        if let Some(previous) = self.previous.take() {
+            CURRENT.with(|current| *current.borrow_mut() = previous);
+        }
+    }
+}
+
+/// Port - Pulls request-scoped fields out of an incoming request
+///
+/// `R` is whatever request type the web framework provides. Closures taking
+/// `&R` and returning the fields implement this trait too.
+pub trait ContextExtractor<R: ?Sized> {
+    /// Fields to add to the logging context for `req`
+    fn extract(&self, req: &R) -> Vec<(String, Value)>;
+}
+
+impl<R: ?Sized, F> ContextExtractor<R> for F
+where
+    F: Fn(&R) -> Vec<(String, Value)>,
+{
+    fn extract(&self, req: &R) -> Vec<(String, Value)> {
+        self(req)
+    }
+}
+
+/// Run `f` with the fields extracted from `req` added to the current context
+///
+/// Extractors run in order, so a later extractor's field replaces an
+/// earlier one with the same key. The previous context is restored when
+/// `f` returns. This is the hook for per-request logging middleware:
+///
+/// ```rust
+/// use std::collections::HashMap;
+/// use tyl_logging::{with_extracted_context, ContextExtractor};
+///
+/// type Headers = HashMap<String, String>;
+///
+/// let tenant = |headers: &Headers| match headers.get("x-tenant") {
+///     Some(tenant) => vec![("tenant".to_string(), serde_json::json!(tenant))],
+///     None => Vec::new(),
+/// };
+/// let extractors: Vec<&dyn ContextExtractor<Headers>> = vec![&tenant];
+///
+/// let headers = Headers::from([("x-tenant".to_string(), "acme".to_string())]);
+/// with_extracted_context(&headers, &extractors, || {
+///     // records logged through a ContextLogger here carry tenant "acme"
+/// });
+/// ```
+pub fn with_extracted_context<R: ?Sized, T>(
+    req: &R,
+    extractors: &[&dyn ContextExtractor<R>],
+    f: impl FnOnce() -> T,
+) -> T {
+    let mut context = capture_context();
+    for extractor in extractors {
+        for (key, value) in extractor.extract(req) {
+            context.fields.insert(key, value);
+        }
+    }
+    let _guard = context.enter();
+    f()
+}
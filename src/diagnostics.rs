@@ -0,0 +1,86 @@
+//! Internal diagnostics
+//!
+//! Loggers report recoverable problems of their own, such as dropped
+//! records or a failing sink, as throttled warnings on stderr rather than
+//! failing silently. The warnings bypass the logging pipeline, since that
+//! pipeline is usually what has gone wrong.
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::io::Write;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Minimum time between two reports of the same issue by default
+pub const DEFAULT_DIAGNOSTIC_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Issue reported when a logger discards records it could not deliver
+pub const ISSUE_DROPPED_RECORDS: &str = "dropped_records";
+
+/// Issue reported when writing to a sink fails
+pub const ISSUE_SINK_WRITE_FAILED: &str = "sink_write_failed";
+
+/// Issue reported when a record field overrides a different context value
+pub const ISSUE_CONTEXT_FIELD_CONFLICT: &str = "context_field_conflict";
+
+/// Throttled warnings about a logger's own misconfiguration or failures
+///
+/// Each issue is reported at most once per interval; later occurrences
+/// within the interval are silently counted out. Lines look like
+/// `[tyl-logging] WARN dropped_records: receiver disconnected`.
+pub struct Diagnostics {
+    interval: Duration,
+    writer: Mutex<Box<dyn Write + Send>>,
+    last_reported: Mutex<HashMap<&'static str, Instant>>,
+}
+
+impl Diagnostics {
+    /// Report to stderr, at most once per issue per minute
+    pub fn new() -> Self {
+        Self::with_writer(std::io::stderr())
+    }
+
+    /// Report to `writer` instead of stderr
+    pub fn with_writer(writer: impl Write + Send + 'static) -> Self {
+        Self {
+            interval: DEFAULT_DIAGNOSTIC_INTERVAL,
+            writer: Mutex::new(Box::new(writer)),
+            last_reported: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Set the minimum time between reports of the same issue
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Report `issue`, unless it was already reported within the interval
+    ///
+    /// Returns `true` if a line was written.
+    pub fn report(&self, issue: &'static str, detail: impl Display) -> bool {
+        let now = Instant::now();
+        {
+            let mut last_reported = self.last_reported.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some(last) = last_reported.get(issue) {
+                if now.duration_since(*last) < self.interval {
+                    return false;
+                }
+            }
+            last_reported.insert(issue, now);
+        }
+        let line = format!("[tyl-logging] WARN {issue}: {detail}\n");
+        let _ = self
+            .writer
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .write_all(line.as_bytes());
+        true
+    }
+}
+
+impl Default for Diagnostics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
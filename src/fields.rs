@@ -0,0 +1,178 @@
+//! Field key handling
+//!
+//! Some log aggregators reject field keys containing dots, spaces, or a
+//! leading digit. This module provides a configurable validator that either
+//! rejects such keys or rewrites them into an accepted form, along with
+//! other per-key field handling.
+
+use crate::record::LogRecord;
+use crate::LoggingResult;
+use serde_json::Value;
+use std::collections::HashMap;
+use tyl_errors::TylError;
+
+/// Field keys with a meaning of their own to formatters and adapters
+///
+/// Rewriting adapters such as [`FieldPrefixLogger`](crate::FieldPrefixLogger)
+/// leave these keys untouched.
+pub const RESERVED_FIELD_KEYS: &[&str] = &["signature_id"];
+
+/// What to do with a field key that fails validation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldKeyPolicy {
+    /// Replace invalid characters with `_` (and prefix a leading digit with `_`)
+    Sanitize,
+    /// Return a validation error
+    Reject,
+}
+
+/// What to do when a field is added under a key the record already has
+///
+/// Set per record with [`LogRecord::with_collision_policy`](crate::LogRecord::with_collision_policy).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FieldCollisionPolicy {
+    /// Replace the existing value
+    #[default]
+    Overwrite,
+    /// Keep the existing value and discard the new one
+    KeepFirst,
+    /// Store the new value under the first free `key_2`, `key_3`, ...
+    RenameWithSuffix,
+    /// Reject the new value: [`LogRecord::try_add_field`](crate::LogRecord::try_add_field)
+    /// returns an error and `add_field` discards it
+    Error,
+}
+
+/// What [`LogRecord::add_option`](crate::LogRecord::add_option) does with `None`
+///
+/// Set per record with [`LogRecord::with_none_policy`](crate::LogRecord::with_none_policy).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NoneFieldPolicy {
+    /// Store the key with a JSON `null`
+    #[default]
+    Null,
+    /// Leave the key out
+    Omit,
+}
+
+/// Validates field keys against a per-character rule
+#[derive(Debug, Clone, Copy)]
+pub struct FieldKeyValidator {
+    policy: FieldKeyPolicy,
+    allowed: fn(char) -> bool,
+}
+
+impl FieldKeyValidator {
+    /// Create a validator accepting ASCII letters, digits, and `_`
+    pub fn new(policy: FieldKeyPolicy) -> Self {
+        Self {
+            policy,
+            allowed: |c| c.is_ascii_alphanumeric() || c == '_',
+        }
+    }
+
+    /// Use a custom rule for which characters a key may contain
+    pub fn with_allowed_chars(mut self, allowed: fn(char) -> bool) -> Self {
+        self.allowed = allowed;
+        self
+    }
+
+    /// Get the configured policy
+    pub fn policy(&self) -> FieldKeyPolicy {
+        self.policy
+    }
+
+    /// Check whether `key` is accepted as-is
+    pub fn is_valid(&self, key: &str) -> bool {
+        !key.is_empty()
+            && !key.starts_with(|c: char| c.is_ascii_digit())
+            && key.chars().all(self.allowed)
+    }
+
+    /// Validate `key`, returning the key to store under
+    pub fn validate(&self, key: &str) -> LoggingResult<String> {
+        if self.is_valid(key) {
+            return Ok(key.to_string());
+        }
+        match self.policy {
+            FieldKeyPolicy::Reject => Err(TylError::validation(
+                key,
+                "field key must be non-empty, not start with a digit, and use only allowed characters",
+            )),
+            FieldKeyPolicy::Sanitize => Ok(self.sanitize(key)),
+        }
+    }
+
+    fn sanitize(&self, key: &str) -> String {
+        let mut sanitized: String = key
+            .chars()
+            .map(|c| if (self.allowed)(c) { c } else { '_' })
+            .collect();
+        if sanitized.is_empty() || sanitized.starts_with(|c: char| c.is_ascii_digit()) {
+            sanitized.insert(0, '_');
+        }
+        sanitized
+    }
+}
+
+impl Default for FieldKeyValidator {
+    fn default() -> Self {
+        Self::new(FieldKeyPolicy::Sanitize)
+    }
+}
+
+type FieldTransform = Box<dyn Fn(&Value) -> Value + Send + Sync>;
+
+/// Per-key transforms applied to field values when a record is emitted
+///
+/// Used by [`FieldSerializingLogger`](crate::FieldSerializingLogger) to
+/// normalize fields whose default serialization is awkward, e.g. turning a
+/// `{"secs": .., "nanos": ..}` duration into milliseconds.
+#[derive(Default)]
+pub struct FieldSerializers {
+    transforms: HashMap<String, FieldTransform>,
+}
+
+impl FieldSerializers {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Transform the value of every field named `key` with `transform`
+    ///
+    /// Registering the same key again replaces the earlier transform.
+    pub fn with(
+        mut self,
+        key: impl Into<String>,
+        transform: impl Fn(&Value) -> Value + Send + Sync + 'static,
+    ) -> Self {
+        self.transforms.insert(key.into(), Box::new(transform));
+        self
+    }
+
+    /// Whether any registered transform applies to `record`
+    pub fn applies_to(&self, record: &LogRecord) -> bool {
+        record
+            .fields()
+            .keys()
+            .any(|key| self.transforms.contains_key(key))
+    }
+
+    /// Replace each field that has a registered transform with its output
+    pub fn apply(&self, record: &mut LogRecord) {
+        for (key, value) in record.fields_mut().iter_mut() {
+            if let Some(transform) = self.transforms.get(key) {
+                *value = transform(value);
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for FieldSerializers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FieldSerializers")
+            .field("keys", &self.transforms.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
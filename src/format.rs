@@ -0,0 +1,740 @@
+//! Record formatting
+//!
+//! Formatters turn a [`LogRecord`] into the bytes of one output line,
+//! independent of where that line is written. The built-in adapters pair a
+//! formatter with a writer through [`SinkLogger`](crate::loggers::SinkLogger),
+//! and custom sinks can reuse the same formatters.
+
+use crate::record::{LogLevel, LogRecord};
+use crate::utils::{
+    format_level, format_level_short, format_level_sortable, format_rfc3339, format_timestamp,
+};
+use crate::LoggingResult;
+use serde::Serialize;
+use serde_json::Value;
+use tyl_errors::TylError;
+
+/// Port - Renders a record as one line of output, without the line terminator
+///
+/// A custom logger picks a formatter and only owns its sink, reusing the
+/// built-in level names, timestamps and escaping:
+///
+/// ```rust
+/// use tyl_logging::{Formatter, LogfmtFormatter, LogLevel, LogRecord};
+///
+/// let line = LogfmtFormatter::default()
+///     .format_string(&LogRecord::new(LogLevel::Info, "ready").with_timestamp(0));
+/// assert_eq!(line, "ts=0 level=INFO msg=ready");
+/// ```
+pub trait Formatter {
+    /// Format `record` as bytes
+    fn format(&self, record: &LogRecord) -> Vec<u8>;
+
+    /// Format `record` as a string, replacing any invalid UTF-8
+    fn format_string(&self, record: &LogRecord) -> String {
+        String::from_utf8_lossy(&self.format(record)).into_owned()
+    }
+}
+
+/// Line terminator written after each formatted record
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineEnding {
+    /// `\n`
+    #[default]
+    Lf,
+    /// `\r\n`, for collectors that expect Windows line endings
+    Crlf,
+}
+
+impl LineEnding {
+    /// Get the terminator bytes as a string
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LineEnding::Lf => "\n",
+            LineEnding::Crlf => "\r\n",
+        }
+    }
+}
+
+/// ANSI reset sequence written after a colored level
+pub const ANSI_RESET: &str = "\x1b[0m";
+
+/// ANSI style used for each level in colored console output
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColorScheme {
+    styles: [String; 6],
+}
+
+impl Default for ColorScheme {
+    fn default() -> Self {
+        Self {
+            styles: [
+                "\x1b[90m".to_string(),
+                "\x1b[36m".to_string(),
+                "\x1b[32m".to_string(),
+                "\x1b[33m".to_string(),
+                "\x1b[31m".to_string(),
+                "\x1b[1;31m".to_string(),
+            ],
+        }
+    }
+}
+
+impl ColorScheme {
+    /// Create the default scheme (gray, cyan, green, yellow, red, bold red)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use `style` for `level`
+    ///
+    /// `style` must be an SGR escape sequence such as `"\x1b[1;34m"`:
+    /// `ESC [`, then digits separated by `;`, then `m`.
+    pub fn with_style(mut self, level: LogLevel, style: &str) -> LoggingResult<Self> {
+        if !is_sgr_sequence(style) {
+            return Err(TylError::validation(
+                "color_scheme",
+                format!("{style:?} is not an ANSI SGR sequence like \"\\x1b[32m\""),
+            ));
+        }
+        self.styles[level as usize] = style.to_string();
+        Ok(self)
+    }
+
+    /// Get the style for `level`
+    pub fn style(&self, level: LogLevel) -> &str {
+        &self.styles[level as usize]
+    }
+}
+
+fn is_sgr_sequence(style: &str) -> bool {
+    style
+        .strip_prefix("\x1b[")
+        .and_then(|rest| rest.strip_suffix('m'))
+        .is_some_and(|params| {
+            !params.is_empty()
+                && params
+                    .split(';')
+                    .all(|p| !p.is_empty() && p.chars().all(|c| c.is_ascii_digit()))
+        })
+}
+
+/// Prefix the console formatter puts on each continuation line of a message
+pub const CONTINUATION_INDENT: &str = "    ";
+
+fn indent_continuation_lines(message: &str) -> std::borrow::Cow<'_, str> {
+    if message.contains('\n') {
+        message
+            .replace('\n', &format!("\n{}", CONTINUATION_INDENT))
+            .into()
+    } else {
+        message.into()
+    }
+}
+
+/// Human-readable `[timestamp] LEVEL: message` lines
+///
+/// Records with an empty message render as `[timestamp] LEVEL key=value ...`
+/// instead, or just `[timestamp] LEVEL` when they have no fields either.
+/// Continuation lines of a multi-line message, such as a stack trace, are
+/// indented by [`CONTINUATION_INDENT`] so they read as part of the record.
+#[derive(Debug, Clone)]
+pub struct ConsoleFormatter {
+    pub(crate) timestamps: bool,
+    pub(crate) colors: Option<ColorScheme>,
+    pub(crate) self_identify: bool,
+    pub(crate) compact_levels: bool,
+    pub(crate) line_prefix: Option<String>,
+}
+
+impl Default for ConsoleFormatter {
+    fn default() -> Self {
+        Self {
+            timestamps: true,
+            colors: None,
+            self_identify: false,
+            compact_levels: false,
+            line_prefix: None,
+        }
+    }
+}
+
+impl ConsoleFormatter {
+    /// Adapter name reported by [`with_self_identify`](Self::with_self_identify)
+    pub const NAME: &'static str = "console";
+
+    /// Output layout version reported by [`with_self_identify`](Self::with_self_identify)
+    pub const FORMAT_VERSION: u32 = 1;
+
+    /// Create a new console formatter
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Include the `[timestamp]` prefix (on by default)
+    pub fn with_timestamp(mut self, enabled: bool) -> Self {
+        self.timestamps = enabled;
+        self
+    }
+
+    /// Color the level using `scheme` (uncolored by default)
+    pub fn with_color_scheme(mut self, scheme: ColorScheme) -> Self {
+        self.colors = Some(scheme);
+        self
+    }
+
+    /// Render levels as single characters such as `I` and `W` (off by default)
+    pub fn with_compact_levels(mut self, enabled: bool) -> Self {
+        self.compact_levels = enabled;
+        self
+    }
+
+    /// Append ` logger=console format_version=1` to each line (off by default)
+    pub fn with_self_identify(mut self, enabled: bool) -> Self {
+        self.self_identify = enabled;
+        self
+    }
+
+    /// Start each line with `prefix` and a space, e.g. `[payments]`
+    pub fn with_line_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.line_prefix = Some(prefix.into());
+        self
+    }
+}
+
+impl Formatter for ConsoleFormatter {
+    fn format(&self, record: &LogRecord) -> Vec<u8> {
+        let level = if self.compact_levels {
+            format_level_short(record.level()).to_string()
+        } else {
+            format_level(record.level()).to_string()
+        };
+        let level = match &self.colors {
+            Some(scheme) => format!("{}{}{}", scheme.style(record.level()), level, ANSI_RESET),
+            None => level,
+        };
+        // A field-only record shows its fields where the message would be,
+        // rather than a dangling `: `
+        let line = if !record.message().is_empty() {
+            format!("{}: {}", level, indent_continuation_lines(record.message()))
+        } else if !record.fields().is_empty() {
+            format!("{}{}", level, logfmt_fields(record))
+        } else {
+            level
+        };
+        let line = if self.self_identify {
+            format!(
+                "{} logger={} format_version={}",
+                line,
+                Self::NAME,
+                Self::FORMAT_VERSION
+            )
+        } else {
+            line
+        };
+        let line = if self.timestamps {
+            format!("[{}] {}", format_timestamp(record.timestamp()), line)
+        } else {
+            line
+        };
+        match &self.line_prefix {
+            Some(prefix) => format!("{} {}", prefix, line).into_bytes(),
+            None => line.into_bytes(),
+        }
+    }
+}
+
+/// Key the JSON formatter nests custom fields under by default
+pub const DEFAULT_FIELDS_KEY: &str = "fields";
+
+/// Field the JSON formatter records each line's byte length under
+pub const SIZE_FIELD: &str = "_size_bytes";
+
+/// Nesting depth the JSON formatter keeps in field values by default
+pub const DEFAULT_MAX_FIELD_DEPTH: usize = 32;
+
+/// Placeholder for field value structure nested beyond the maximum depth
+pub const MAX_DEPTH_MARKER: &str = "[max depth exceeded]";
+
+/// One JSON object per record
+///
+/// Top-level keys always appear in the same order: `timestamp`, `level`,
+/// `message`, the fields object, `request_id`, then whichever of `tags`,
+/// `logger`, `format_version` and `prefix` apply.
+#[derive(Debug, Clone)]
+pub struct JsonFormatter {
+    pub(crate) sortable_levels: bool,
+    pub(crate) fields_key: String,
+    pub(crate) timestamps: bool,
+    pub(crate) self_identify: bool,
+    pub(crate) object_level: bool,
+    pub(crate) dual_timestamp: bool,
+    pub(crate) max_depth: usize,
+    pub(crate) size_field: bool,
+    pub(crate) omit_empty_message: bool,
+    pub(crate) line_prefix: Option<String>,
+}
+
+impl Default for JsonFormatter {
+    fn default() -> Self {
+        Self {
+            sortable_levels: false,
+            fields_key: DEFAULT_FIELDS_KEY.to_string(),
+            timestamps: true,
+            self_identify: false,
+            object_level: false,
+            dual_timestamp: false,
+            max_depth: DEFAULT_MAX_FIELD_DEPTH,
+            size_field: false,
+            omit_empty_message: false,
+            line_prefix: None,
+        }
+    }
+}
+
+impl JsonFormatter {
+    /// Adapter name reported by [`with_self_identify`](Self::with_self_identify)
+    pub const NAME: &'static str = "json";
+
+    /// Output layout version reported by [`with_self_identify`](Self::with_self_identify)
+    pub const FORMAT_VERSION: u32 = 1;
+
+    /// Create a new JSON formatter
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Emit levels as severity-sortable strings such as `"2_INFO"`
+    pub fn with_sortable_levels(mut self, enabled: bool) -> Self {
+        self.sortable_levels = enabled;
+        self
+    }
+
+    /// Nest custom fields under `name` instead of `"fields"`
+    pub fn with_fields_key(mut self, name: &str) -> Self {
+        self.fields_key = name.to_string();
+        self
+    }
+
+    /// Include the `"timestamp"` key (on by default)
+    pub fn with_timestamp(mut self, enabled: bool) -> Self {
+        self.timestamps = enabled;
+        self
+    }
+
+    /// Add `"logger": "json"` and `"format_version"` keys (off by default)
+    pub fn with_self_identify(mut self, enabled: bool) -> Self {
+        self.self_identify = enabled;
+        self
+    }
+
+    /// Emit the level as `{"name": "WARN", "value": 3}` instead of a string
+    pub fn with_object_level(mut self, enabled: bool) -> Self {
+        self.object_level = enabled;
+        self
+    }
+
+    /// Emit `"timestamp"` as epoch milliseconds plus an RFC 3339 `"timestamp_iso"`
+    pub fn with_dual_timestamp(mut self, enabled: bool) -> Self {
+        self.dual_timestamp = enabled;
+        self
+    }
+
+    /// Replace arrays and objects nested deeper than `depth` within a field
+    /// value with `"[max depth exceeded]"` (defaults to 32)
+    pub fn with_max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = depth;
+        self
+    }
+
+    /// Add a `"_size_bytes"` field holding the length of the line itself
+    pub fn with_size_field(mut self, enabled: bool) -> Self {
+        self.size_field = enabled;
+        self
+    }
+
+    /// Leave out the `"message"` key when the message is empty (off by default)
+    pub fn with_omit_empty_message(mut self, enabled: bool) -> Self {
+        self.omit_empty_message = enabled;
+        self
+    }
+
+    /// Add a `"prefix"` key holding `prefix`, e.g. `[payments]`
+    ///
+    /// The JSON counterpart of [`ConsoleFormatter::with_line_prefix`]: lines
+    /// stay parseable and can still be grepped for the prefix.
+    pub fn with_line_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.line_prefix = Some(prefix.into());
+        self
+    }
+
+    fn level_str(&self, level: LogLevel) -> &'static str {
+        if self.sortable_levels {
+            format_level_sortable(level)
+        } else {
+            format_level(level)
+        }
+    }
+
+    fn level_value(&self, level: LogLevel) -> Value {
+        if self.object_level {
+            serde_json::json!({ "name": self.level_str(level), "value": level as u8 })
+        } else {
+            Value::from(self.level_str(level))
+        }
+    }
+
+    /// Build the JSON object for `record`
+    ///
+    /// Keys are emitted in a fixed order: `timestamp` (and `timestamp_iso`),
+    /// `level`, `message` (unless omitted), the fields key, `request_id`, then the optional
+    /// `tags`, `logger`, `format_version` and `prefix`.
+    pub(crate) fn to_json(&self, record: &LogRecord) -> JsonObject {
+        let mut object = JsonObject::default();
+        if self.timestamps && self.dual_timestamp {
+            object.insert("timestamp", record.timestamp().saturating_mul(1000));
+            object.insert("timestamp_iso", format_rfc3339(record.timestamp()));
+        } else if self.timestamps {
+            object.insert("timestamp", record.timestamp());
+        }
+        object.insert("level", self.level_value(record.level()));
+        if !(self.omit_empty_message && record.message().is_empty()) {
+            object.insert("message", record.message());
+        }
+        let fields: serde_json::Map<String, Value> = record
+            .fields()
+            .iter()
+            .map(|(key, value)| (key.clone(), limit_depth(value, self.max_depth)))
+            .collect();
+        object.insert(&self.fields_key, fields);
+        object.insert_absent("request_id", record.request_id());
+        if !record.tags().is_empty() {
+            object.insert("tags", record.tags());
+        }
+        if self.self_identify {
+            object.insert("logger", Self::NAME);
+            object.insert("format_version", Self::FORMAT_VERSION);
+        }
+        if let Some(prefix) = &self.line_prefix {
+            object.insert("prefix", prefix.as_str());
+        }
+        object
+    }
+}
+
+/// Copy `value`, cutting off arrays and objects more than `depth` levels deep
+///
+/// Recursion is bounded by `depth`, so arbitrarily deep input cannot
+/// exhaust the stack here or when the copy is serialized.
+fn limit_depth(value: &Value, depth: usize) -> Value {
+    match value {
+        Value::Array(_) | Value::Object(_) if depth == 0 => Value::from(MAX_DEPTH_MARKER),
+        Value::Array(items) => items
+            .iter()
+            .map(|item| limit_depth(item, depth - 1))
+            .collect(),
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(key, item)| (key.clone(), limit_depth(item, depth - 1)))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+/// A JSON object that serializes its keys in insertion order
+///
+/// `serde_json::Map` sorts its keys, which would put `fields` ahead of
+/// `timestamp`; the JSON formatters build their top-level object with this
+/// instead so the layout is stable.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct JsonObject(Vec<(String, Value)>);
+
+impl JsonObject {
+    /// Set `key`, replacing an existing value in place
+    pub(crate) fn insert(&mut self, key: &str, value: impl Into<Value>) {
+        let value = value.into();
+        match self.0.iter_mut().find(|(k, _)| k == key) {
+            Some((_, existing)) => *existing = value,
+            None => self.0.push((key.to_string(), value)),
+        }
+    }
+
+    /// Set `key` unless it is already present
+    pub(crate) fn insert_absent(&mut self, key: &str, value: impl Into<Value>) {
+        if !self.0.iter().any(|(k, _)| k == key) {
+            self.0.push((key.to_string(), value.into()));
+        }
+    }
+}
+
+impl Serialize for JsonObject {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (key, value) in &self.0 {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+}
+
+impl Formatter for JsonFormatter {
+    fn format(&self, record: &LogRecord) -> Vec<u8> {
+        if !self.size_field {
+            return serialize_line(&self.to_json(record), record).into_bytes();
+        }
+        // The size is part of the line it measures, so re-serialize with
+        // each measured length until it no longer changes. Only the digit
+        // count of the size can move the length, so this settles within a
+        // few passes.
+        let mut record = record.clone();
+        let mut size = 0;
+        loop {
+            record
+                .fields_mut()
+                .insert(SIZE_FIELD.to_string(), Value::from(size));
+            let line = serialize_line(&self.to_json(&record), &record);
+            if line.len() == size {
+                return line.into_bytes();
+            }
+            size = line.len();
+        }
+    }
+}
+
+/// Serialize `value` as the JSON line for `record`
+///
+/// If serialization fails, a minimal fallback record carrying the original
+/// message and the serializer error is returned instead, so the output line
+/// is always valid JSON.
+pub(crate) fn serialize_line<T: Serialize + ?Sized>(value: &T, record: &LogRecord) -> String {
+    serde_json::to_string(value).unwrap_or_else(|err| {
+        serde_json::json!({
+            "timestamp": record.timestamp(),
+            "level": format_level(LogLevel::Error),
+            "message": "log serialization failed",
+            "orig_message": record.message(),
+            "error": err.to_string()
+        })
+        .to_string()
+    })
+}
+
+/// Join rendered JSON lines into one `[...]` array
+///
+/// Each line is rendered on its own (normally through [`serialize_line`]),
+/// so a record that fails to serialize costs only its own entry, not the
+/// whole batch.
+pub(crate) fn json_array(lines: &[String]) -> String {
+    let mut array =
+        String::with_capacity(lines.iter().map(|line| line.len() + 1).sum::<usize>() + 2);
+    array.push('[');
+    for (index, line) in lines.iter().enumerate() {
+        if index > 0 {
+            array.push(',');
+        }
+        array.push_str(line);
+    }
+    array.push(']');
+    array
+}
+
+/// `key=value` logfmt lines, fields sorted by key
+#[derive(Debug, Clone, Default)]
+pub struct LogfmtFormatter {
+    pub(crate) self_identify: bool,
+}
+
+impl LogfmtFormatter {
+    /// Adapter name reported by [`with_self_identify`](Self::with_self_identify)
+    pub const NAME: &'static str = "logfmt";
+
+    /// Output layout version reported by [`with_self_identify`](Self::with_self_identify)
+    pub const FORMAT_VERSION: u32 = 1;
+
+    /// Create a new logfmt formatter
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `logger=logfmt` and `format_version` pairs (off by default)
+    pub fn with_self_identify(mut self, enabled: bool) -> Self {
+        self.self_identify = enabled;
+        self
+    }
+}
+
+/// Quote a logfmt value when it contains spaces, quotes, `=` or control characters
+fn quote_logfmt(value: &str) -> String {
+    let needs_quotes = value.is_empty()
+        || value
+            .chars()
+            .any(|c| c == ' ' || c == '=' || c == '"' || c == '\\' || c.is_control());
+    if !needs_quotes {
+        return value.to_string();
+    }
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Render `record`'s fields as ` key=value` pairs sorted by key
+fn logfmt_fields(record: &LogRecord) -> String {
+    let mut fields: Vec<_> = record.fields().iter().collect();
+    fields.sort_by(|a, b| a.0.cmp(b.0));
+    let mut rendered = String::new();
+    for (key, value) in fields {
+        let value = match value {
+            Value::String(s) => quote_logfmt(s),
+            other => quote_logfmt(&other.to_string()),
+        };
+        rendered.push_str(&format!(" {}={}", key, value));
+    }
+    rendered
+}
+
+impl Formatter for LogfmtFormatter {
+    fn format(&self, record: &LogRecord) -> Vec<u8> {
+        let mut line = format!(
+            "ts={} level={} msg={}",
+            format_timestamp(record.timestamp()),
+            format_level(record.level()),
+            quote_logfmt(record.message())
+        );
+        if let Some(request_id) = record.request_id() {
+            line.push_str(&format!(" request_id={}", quote_logfmt(request_id)));
+        }
+        if !record.tags().is_empty() {
+            line.push_str(&format!(" tags={}", quote_logfmt(&record.tags().join(","))));
+        }
+        line.push_str(&logfmt_fields(record));
+        if self.self_identify {
+            line.push_str(&format!(
+                " logger={} format_version={}",
+                Self::NAME,
+                Self::FORMAT_VERSION
+            ));
+        }
+        line.into_bytes()
+    }
+}
+
+/// ArcSight Common Event Format lines for SIEM ingestion
+///
+/// `CEF:0|Vendor|Product|Version|SignatureID|Name|Severity|extension`, where
+/// the signature ID is the record's `signature_id` field (or the level name
+/// when absent), the name is the message, and the severity follows
+/// [`CefFormatter::severity`]. The extension holds `rt` (milliseconds since
+/// the epoch), `request_id` when present, and the remaining fields sorted by
+/// key.
+#[derive(Debug, Clone)]
+pub struct CefFormatter {
+    vendor: String,
+    product: String,
+    version: String,
+}
+
+impl CefFormatter {
+    /// Create a formatter identifying the device as `vendor`/`product`/`version`
+    pub fn new(
+        vendor: impl Into<String>,
+        product: impl Into<String>,
+        version: impl Into<String>,
+    ) -> Self {
+        Self {
+            vendor: vendor.into(),
+            product: product.into(),
+            version: version.into(),
+        }
+    }
+
+    /// Map a level onto CEF's 0-10 severity scale
+    pub fn severity(level: LogLevel) -> u8 {
+        match level {
+            LogLevel::Trace => 0,
+            LogLevel::Debug => 1,
+            LogLevel::Info => 3,
+            LogLevel::Warn => 6,
+            LogLevel::Error => 8,
+            LogLevel::Fatal => 10,
+        }
+    }
+}
+
+/// Escape a CEF header value: `\` and `|`
+fn escape_cef_header(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '|' => escaped.push_str("\\|"),
+            '\n' | '\r' => escaped.push(' '),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Escape a CEF extension value: `\`, `=`, and line breaks
+fn escape_cef_extension(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '=' => escaped.push_str("\\="),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+impl Formatter for CefFormatter {
+    fn format(&self, record: &LogRecord) -> Vec<u8> {
+        let signature_id = match record.fields().get("signature_id") {
+            Some(Value::String(id)) => id.clone(),
+            Some(other) => other.to_string(),
+            None => format_level(record.level()).to_string(),
+        };
+        let mut line = format!(
+            "CEF:0|{}|{}|{}|{}|{}|{}|rt={}",
+            escape_cef_header(&self.vendor),
+            escape_cef_header(&self.product),
+            escape_cef_header(&self.version),
+            escape_cef_header(&signature_id),
+            escape_cef_header(record.message()),
+            Self::severity(record.level()),
+            record.timestamp().saturating_mul(1000)
+        );
+        if let Some(request_id) = record.request_id() {
+            line.push_str(&format!(" request_id={}", escape_cef_extension(request_id)));
+        }
+        let mut fields: Vec<_> = record
+            .fields()
+            .iter()
+            .filter(|(key, _)| key.as_str() != "signature_id")
+            .collect();
+        fields.sort_by(|a, b| a.0.cmp(b.0));
+        for (key, value) in fields {
+            let value = match value {
+                Value::String(s) => escape_cef_extension(s),
+                other => escape_cef_extension(&other.to_string()),
+            };
+            line.push_str(&format!(" {}={}", key, value));
+        }
+        line.into_bytes()
+    }
+}
@@ -0,0 +1,91 @@
+//! Process-wide default logger
+//!
+//! Services usually want one logger configured at startup and reachable from
+//! anywhere. This module holds that logger and builds it from the environment.
+
+use crate::config::LoggingConfig;
+use crate::loggers::{build_logger_with_writer, Logger};
+use crate::LoggingResult;
+use std::io::Write;
+use std::sync::{Arc, RwLock};
+use tyl_config::ConfigPlugin;
+
+/// Shared, thread-safe logger handle
+pub type SharedLogger = Arc<dyn Logger + Send + Sync>;
+
+static GLOBAL_LOGGER: RwLock<Option<SharedLogger>> = RwLock::new(None);
+
+/// Install `logger` as the global logger, replacing any previous one
+pub fn set_global_logger(logger: SharedLogger) {
+    *GLOBAL_LOGGER.write().unwrap_or_else(|e| e.into_inner()) = Some(logger);
+}
+
+/// Get the global logger, if one has been installed
+pub fn global_logger() -> Option<SharedLogger> {
+    GLOBAL_LOGGER
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+}
+
+/// Remove and return the global logger, leaving none installed
+pub fn take_global_logger() -> Option<SharedLogger> {
+    GLOBAL_LOGGER
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .take()
+}
+
+/// Restores the previous global logger when dropped, including on unwind
+struct RestoreGlobal(Option<SharedLogger>);
+
+impl Drop for RestoreGlobal {
+    fn drop(&mut self) {
+        *GLOBAL_LOGGER.write().unwrap_or_else(|e| e.into_inner()) = self.0.take();
+    }
+}
+
+/// Run `f` with `logger` installed globally, then restore the previous one
+///
+/// The previous logger (or its absence) is restored even if `f` panics,
+/// which keeps tests that touch the global logger hermetic. Note that the
+/// global logger is still process-wide while `f` runs, so concurrent tests
+/// relying on it must be serialized.
+pub fn with_global_logger<F, R>(logger: SharedLogger, f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    let previous = GLOBAL_LOGGER
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .replace(logger);
+    let _restore = RestoreGlobal(previous);
+    f()
+}
+
+/// Build a stdout logger from the environment and install it globally
+///
+/// Reads the same variables as [`LoggingConfig`]'s `merge_env` (`LOG_LEVEL`,
+/// `SERVICE_NAME`, `ENVIRONMENT`, `LOG_FORMAT`, optionally `TYL_`-prefixed).
+/// `LOG_FORMAT` accepts `console`, `json`, or `logfmt`; when unset the format
+/// is console in `Development` and JSON otherwise.
+///
+/// ```rust,no_run
+/// use tyl_logging::{init_from_env, LogLevel, LogRecord};
+///
+/// let logger = init_from_env().expect("valid logging environment");
+/// logger.log(&LogRecord::new(LogLevel::Info, "service started"));
+/// ```
+pub fn init_from_env() -> LoggingResult<SharedLogger> {
+    init_from_env_with_writer(std::io::stdout())
+}
+
+/// Like [`init_from_env`], but the logger writes to `writer`
+pub fn init_from_env_with_writer(
+    writer: impl Write + Send + 'static,
+) -> LoggingResult<SharedLogger> {
+    let config = LoggingConfig::new("app").load_from_env()?;
+    let logger: SharedLogger = Arc::new(build_logger_with_writer(&config, writer)?);
+    set_global_logger(logger.clone());
+    Ok(logger)
+}
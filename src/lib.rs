@@ -37,9 +37,27 @@
 //! ```
 
 // Module declarations
+pub mod audit;
+pub mod capture;
+#[cfg(any(feature = "tracing-compat", feature = "log-compat"))]
+mod compat;
 pub mod config;
+pub mod context;
+pub mod diagnostics;
+pub mod fields;
+pub mod format;
+pub mod global;
 pub mod loggers;
+mod macros;
+pub mod once;
+pub mod panic;
+pub mod pipeline;
 pub mod record;
+pub mod result;
+#[cfg(all(unix, feature = "signal"))]
+pub mod signal;
+pub mod startup;
+pub mod timing;
 pub mod utils;
 
 // TYL Framework imports
@@ -49,9 +67,54 @@ use tyl_errors::TylResult;
 pub type LoggingResult<T> = TylResult<T>;
 
 // Re-exports for public API
-pub use config::{Environment, LoggingConfig};
-pub use loggers::{ConsoleLogger, JsonLogger, Logger};
+pub use audit::AuditRecord;
+pub use capture::{capture_thread_logs, ThreadCaptureLogger};
+pub use config::{Environment, LevelFilter, LogFormat, LoggingConfig, TARGET_FIELD};
+pub use context::{
+    capture_context, with_extracted_context, ContextExtractor, ContextGuard, LogContext,
+};
+pub use diagnostics::Diagnostics;
+pub use fields::{
+    FieldCollisionPolicy, FieldKeyPolicy, FieldKeyValidator, FieldSerializers, NoneFieldPolicy,
+    RESERVED_FIELD_KEYS,
+};
+pub use format::{
+    CefFormatter, ColorScheme, ConsoleFormatter, Formatter, JsonFormatter, LogfmtFormatter,
+};
+pub use global::{
+    global_logger, init_from_env, init_from_env_with_writer, set_global_logger, take_global_logger,
+    with_global_logger, SharedLogger,
+};
+#[cfg(feature = "http-client")]
+pub use loggers::HttpLogger;
+#[cfg(feature = "s3")]
+pub use loggers::ObjectStoreLogger;
+#[cfg(feature = "otel")]
+pub use loggers::OtlpLogger;
+#[cfg(feature = "regex")]
+pub use loggers::PatternRedactingLogger;
+#[cfg(feature = "protobuf")]
+pub use loggers::ProtobufLogger;
+#[cfg(feature = "slog")]
+pub use loggers::SlogAdapter;
+pub use loggers::{
+    build_logger, build_logger_with_writer, tenant_from_field, CefLogger, ChannelAck,
+    ChannelLogger, CircuitBreakerLogger, ConfiguredLogger, ConsoleLogger, ContextLogger,
+    DedupLogger, DynamicLevelLogger, EnrichingLogger, EscalatingLogger, FieldPrefixLogger,
+    FieldSerializingLogger, JsonBatchLogger, JsonLogger, LevelCounter, LevelFilterLogger,
+    LevelMapLogger, LineEnding, LogfmtLogger, Logger, LoggerExt, MultiLogger,
+    QuietUntilErrorLogger, RateLimitingLogger, RedactingLogger, RequestBufferLogger,
+    ResourceLogger, SamplePriority, SamplingLogger, ShardedConsoleLogger, SinkLogger, SpanGuard,
+    SpanLogger, StatsLogger, SwappableLogger, TenantKey, TenantRouter, ToggleLogger,
+    TraceSamplingLogger, UptimeLogger,
+};
+pub use once::log_once;
+pub use panic::{install_panic_hook, panic_record};
+pub use pipeline::LoggerPipeline;
 pub use record::{LogLevel, LogRecord};
+pub use result::log_result;
+pub use startup::log_startup_banner;
+pub use timing::{log_timed, log_timed_result, TimedScope};
 pub use utils::generate_request_id;
 
 #[cfg(test)]
@@ -154,6 +217,7 @@ mod tests {
         // Given: different log levels
         // When: comparing levels
         // Then: should follow expected ordering
+        assert!(LogLevel::Fatal > LogLevel::Error);
         assert!(LogLevel::Error > LogLevel::Warn);
         assert!(LogLevel::Warn > LogLevel::Info);
         assert!(LogLevel::Info > LogLevel::Debug);
@@ -186,4 +250,26 @@ mod tests {
         assert_eq!(logger.messages.borrow().len(), 1);
         assert_eq!(logger.messages.borrow()[0], "Test message");
     }
+
+    #[test]
+    fn test_json_serialization_failure_falls_back_to_valid_json() {
+        // Given: a value whose serialization always fails
+        struct Unserializable;
+
+        impl serde::Serialize for Unserializable {
+            fn serialize<S: serde::Serializer>(&self, _: S) -> Result<S::Ok, S::Error> {
+                Err(serde::ser::Error::custom("boom"))
+            }
+        }
+
+        // When: rendering it as a JSON log line
+        let record = LogRecord::new(LogLevel::Info, "original message");
+        let line = format::serialize_line(&Unserializable, &record);
+
+        // Then: should emit a valid fallback record
+        let parsed: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(parsed["level"], "ERROR");
+        assert_eq!(parsed["message"], "log serialization failed");
+        assert_eq!(parsed["orig_message"], "original message");
+    }
 }
@@ -0,0 +1,49 @@
+//! CEF logger implementation
+//!
+//! Provides ArcSight Common Event Format output for SIEM ingestion.
+
+use super::{Logger, SinkLogger};
+use crate::format::CefFormatter;
+use crate::record::LogRecord;
+use std::io::Write;
+
+/// Adapter - Common Event Format logger for SIEMs
+pub struct CefLogger {
+    sink: SinkLogger<CefFormatter, Box<dyn Write + Send>>,
+}
+
+impl CefLogger {
+    /// Create a CEF logger writing to stdout, identifying the device as
+    /// `vendor`/`product`/`version`
+    pub fn new(
+        vendor: impl Into<String>,
+        product: impl Into<String>,
+        version: impl Into<String>,
+    ) -> Self {
+        Self::with_formatter(
+            CefFormatter::new(vendor, product, version),
+            std::io::stdout(),
+        )
+    }
+
+    /// Create a CEF logger writing one event per line to `writer`
+    pub fn with_formatter(formatter: CefFormatter, writer: impl Write + Send + 'static) -> Self {
+        Self {
+            sink: SinkLogger::new(formatter, Box::new(writer)),
+        }
+    }
+}
+
+impl Logger for CefLogger {
+    fn log(&self, record: &LogRecord) {
+        self.sink.log(record);
+    }
+
+    fn log_batch(&self, records: &[LogRecord]) {
+        self.sink.log_batch(records);
+    }
+
+    fn flush(&self) {
+        self.sink.flush();
+    }
+}
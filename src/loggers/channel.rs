@@ -0,0 +1,176 @@
+//! Channel logger
+//!
+//! Hands records to a user-owned pipeline through a standard channel, so they
+//! can be drained and processed elsewhere. A consumer that acknowledges the
+//! records it has handled lets [`Logger::barrier`] wait for it.
+
+use super::Logger;
+use crate::diagnostics::{Diagnostics, ISSUE_DROPPED_RECORDS};
+use crate::record::LogRecord;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::Sender;
+use std::sync::{Arc, Condvar, Mutex};
+
+#[derive(Default)]
+struct AckState {
+    acked: u64,
+    holders: usize,
+}
+
+#[derive(Default)]
+struct Progress {
+    state: Mutex<AckState>,
+    changed: Condvar,
+}
+
+/// Handle for the consumer of a [`ChannelLogger`] to report handled records
+///
+/// Call [`ack`](Self::ack) once per record received, after handling it.
+/// While any handle is alive, [`Logger::barrier`] on the logger blocks until
+/// every record sent so far has been acknowledged; once all handles are
+/// dropped it stops waiting.
+pub struct ChannelAck {
+    progress: Arc<Progress>,
+}
+
+impl ChannelAck {
+    fn new(progress: Arc<Progress>) -> Self {
+        progress
+            .state
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .holders += 1;
+        Self { progress }
+    }
+
+    /// Report one more record as handled
+    pub fn ack(&self) {
+        self.progress
+            .state
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .acked += 1;
+        self.progress.changed.notify_all();
+    }
+}
+
+impl Clone for ChannelAck {
+    fn clone(&self) -> Self {
+        Self::new(Arc::clone(&self.progress))
+    }
+}
+
+impl Drop for ChannelAck {
+    fn drop(&mut self) {
+        self.progress
+            .state
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .holders -= 1;
+        self.progress.changed.notify_all();
+    }
+}
+
+/// Adapter - Sends a clone of each record into an `mpsc` channel
+///
+/// If the receiver has been dropped, records are discarded, counted in
+/// [`ChannelLogger::dropped`], and reported as a throttled diagnostic.
+///
+/// For [`Logger::barrier`] to wait on the consumer, take an
+/// [`acknowledger`](Self::acknowledger) before logging and have the consumer
+/// acknowledge each record:
+///
+/// ```rust
+/// use std::sync::mpsc;
+/// use tyl_logging::{ChannelLogger, LogLevel, LogRecord, Logger};
+///
+/// let (sender, receiver) = mpsc::channel();
+/// let logger = ChannelLogger::new(sender);
+/// let ack = logger.acknowledger();
+/// let consumer = std::thread::spawn(move || {
+///     for record in receiver {
+///         // ... ship the record ...
+///         ack.ack();
+///     }
+/// });
+///
+/// logger.log(&LogRecord::new(LogLevel::Info, "queued"));
+/// logger.barrier(); // returns once the consumer has acknowledged it
+/// # drop(logger);
+/// # consumer.join().unwrap();
+/// ```
+pub struct ChannelLogger {
+    // Sender is only Sync from Rust 1.72; the mutex keeps the logger Sync on older toolchains
+    sender: Mutex<Sender<LogRecord>>,
+    sent: AtomicU64,
+    dropped: AtomicU64,
+    diagnostics: Diagnostics,
+    progress: Arc<Progress>,
+}
+
+impl ChannelLogger {
+    /// Create a logger sending into `sender`
+    pub fn new(sender: Sender<LogRecord>) -> Self {
+        Self {
+            sender: Mutex::new(sender),
+            sent: AtomicU64::new(0),
+            dropped: AtomicU64::new(0),
+            diagnostics: Diagnostics::new(),
+            progress: Arc::default(),
+        }
+    }
+
+    /// Report dropped records through `diagnostics` instead of stderr
+    pub fn with_diagnostics(mut self, diagnostics: Diagnostics) -> Self {
+        self.diagnostics = diagnostics;
+        self
+    }
+
+    /// Get a handle for the consumer to acknowledge handled records
+    ///
+    /// Take it before logging: records sent earlier are counted as pending
+    /// too, so a consumer that cannot acknowledge them would hold up
+    /// [`Logger::barrier`] until the handle is dropped.
+    pub fn acknowledger(&self) -> ChannelAck {
+        ChannelAck::new(Arc::clone(&self.progress))
+    }
+
+    /// Number of records discarded because the receiver was gone
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+}
+
+impl Logger for ChannelLogger {
+    fn log(&self, record: &LogRecord) {
+        let sender = self.sender.lock().unwrap_or_else(|e| e.into_inner());
+        if sender.send(record.clone()).is_ok() {
+            self.sent.fetch_add(1, Ordering::Relaxed);
+        } else {
+            let dropped = self.dropped.fetch_add(1, Ordering::Relaxed) + 1;
+            self.diagnostics.report(
+                ISSUE_DROPPED_RECORDS,
+                format_args!("channel receiver disconnected, {dropped} records dropped so far"),
+            );
+        }
+    }
+
+    /// Wait until the consumer has acknowledged every record sent so far
+    ///
+    /// Returns at once if no [`ChannelAck`] is alive.
+    fn barrier(&self) {
+        let target = self.sent.load(Ordering::Relaxed);
+        let mut state = self
+            .progress
+            .state
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        while state.holders > 0 && state.acked < target {
+            state = self
+                .progress
+                .changed
+                .wait(state)
+                .unwrap_or_else(|e| e.into_inner());
+        }
+    }
+}
@@ -0,0 +1,100 @@
+//! Output-volume circuit breaker
+//!
+//! Caps the total bytes forwarded per time window, protecting disks and
+//! log pipelines from runaway output during an incident.
+
+use super::Logger;
+use crate::format::{Formatter, JsonFormatter};
+use crate::record::{LogLevel, LogRecord};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Message of the notice emitted when the breaker trips
+pub const THROTTLED_MESSAGE: &str = "log output throttled";
+
+struct BreakerState {
+    window_start: Instant,
+    bytes: u64,
+    tripped: bool,
+}
+
+/// Adapter - Stops forwarding once `budget_bytes` is spent within a window
+///
+/// Each record is sized by its JSON rendering. When a record would exceed
+/// the window's budget, the breaker trips: a single `Error` notice
+/// (`"log output throttled"`, with `budget_bytes` and `window_ms` fields) is
+/// forwarded and every further record is dropped until the window resets.
+pub struct CircuitBreakerLogger<L: Logger> {
+    inner: L,
+    budget_bytes: u64,
+    window: Duration,
+    sizer: JsonFormatter,
+    state: Mutex<BreakerState>,
+}
+
+impl<L: Logger> CircuitBreakerLogger<L> {
+    /// Wrap `inner`, forwarding at most `budget_bytes` per `window`
+    pub fn new(inner: L, budget_bytes: u64, window: Duration) -> Self {
+        Self {
+            inner,
+            budget_bytes,
+            window,
+            sizer: JsonFormatter::new(),
+            state: Mutex::new(BreakerState {
+                window_start: Instant::now(),
+                bytes: 0,
+                tripped: false,
+            }),
+        }
+    }
+
+    /// Get the wrapped logger
+    pub fn inner(&self) -> &L {
+        &self.inner
+    }
+
+    /// Whether records are currently being dropped
+    pub fn is_tripped(&self) -> bool {
+        self.state.lock().unwrap_or_else(|e| e.into_inner()).tripped
+    }
+}
+
+impl<L: Logger> Logger for CircuitBreakerLogger<L> {
+    fn log(&self, record: &LogRecord) {
+        let size = self.sizer.format(record).len() as u64;
+        let now = Instant::now();
+        let (allowed, trip) = {
+            let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+            if now.duration_since(state.window_start) >= self.window {
+                state.window_start = now;
+                state.bytes = 0;
+                state.tripped = false;
+            }
+            if state.tripped {
+                (false, false)
+            } else if state.bytes + size <= self.budget_bytes {
+                state.bytes += size;
+                (true, false)
+            } else {
+                state.tripped = true;
+                (false, true)
+            }
+        };
+        if allowed {
+            self.inner.log(record);
+        } else if trip {
+            let mut notice = LogRecord::new(LogLevel::Error, THROTTLED_MESSAGE);
+            notice.add_u64("budget_bytes", self.budget_bytes);
+            notice.add_u64("window_ms", self.window.as_millis() as u64);
+            self.inner.log(&notice);
+        }
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+
+    fn barrier(&self) {
+        self.inner.barrier();
+    }
+}
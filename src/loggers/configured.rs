@@ -0,0 +1,116 @@
+//! Config-driven logger
+//!
+//! Builds a logger from a [`LoggingConfig`], applying its level threshold and
+//! request ID policy before handing records to the underlying adapter.
+
+use super::{ConsoleLogger, JsonLogger, LogfmtLogger, Logger, MultiLogger};
+use crate::capture::tee_to_capture;
+use crate::config::{LogFormat, LoggingConfig};
+use crate::record::{LogLevel, LogRecord};
+use crate::utils::generate_request_id;
+use crate::LoggingResult;
+use std::io::Write;
+use tyl_errors::TylError;
+
+/// Adapter - Logger that applies a [`LoggingConfig`] to every record
+///
+/// With a [`LoggingConfig::level_filter`], records are held to the level for
+/// their [`TARGET_FIELD`](crate::TARGET_FIELD) rather than to `level`.
+///
+/// Records that pass the level threshold are also teed into the current
+/// thread's [`capture_thread_logs`](crate::capture_thread_logs) capture.
+pub struct ConfiguredLogger {
+    config: LoggingConfig,
+    inner: Box<dyn Logger + Send + Sync>,
+}
+
+impl ConfiguredLogger {
+    /// Wrap an existing logger with the given configuration
+    pub fn new(config: LoggingConfig, inner: impl Logger + Send + Sync + 'static) -> Self {
+        Self {
+            config,
+            inner: Box::new(inner),
+        }
+    }
+
+    /// Get the configuration this logger was built from
+    pub fn config(&self) -> &LoggingConfig {
+        &self.config
+    }
+}
+
+impl Logger for ConfiguredLogger {
+    fn log(&self, record: &LogRecord) {
+        let passes = match self.config.level_filter() {
+            Some(filter) => filter.allows(record),
+            None => record.level() >= self.config.level(),
+        };
+        if !passes {
+            return;
+        }
+        if self.config.auto_request_id() && record.request_id().is_none() {
+            let record = record.clone().with_request_id(generate_request_id());
+            tee_to_capture(&record);
+            self.inner.log(&record);
+        } else {
+            tee_to_capture(record);
+            self.inner.log(record);
+        }
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+
+    fn barrier(&self) {
+        self.inner.barrier();
+    }
+}
+
+/// Build a stdout logger from configuration
+///
+/// The adapter follows [`LoggingConfig::format`]: unless set explicitly,
+/// `Development` gets the human-readable [`ConsoleLogger`] and every other
+/// environment the structured [`JsonLogger`]. When
+/// [`LoggingConfig::error_sink_path`] is set, `Error` records are also
+/// appended to that file in the same format.
+///
+/// Fails if the error sink file cannot be opened.
+pub fn build_logger(config: &LoggingConfig) -> LoggingResult<ConfiguredLogger> {
+    build_logger_with_writer(config, std::io::stdout())
+}
+
+/// Build a logger from configuration whose main output goes to `writer`
+pub fn build_logger_with_writer(
+    config: &LoggingConfig,
+    writer: impl Write + Send + 'static,
+) -> LoggingResult<ConfiguredLogger> {
+    let main = format_logger(config.format(), writer);
+    let Some(path) = config.error_sink_path() else {
+        return Ok(ConfiguredLogger::new(config.clone(), main));
+    };
+    let file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|err| {
+            TylError::configuration(format!("cannot open error sink {}: {}", path, err))
+        })?;
+    Ok(ConfiguredLogger::new(
+        config.clone(),
+        MultiLogger::new()
+            .with(main)
+            .with_min_level(format_logger(config.format(), file), LogLevel::Error),
+    ))
+}
+
+fn format_logger(
+    format: LogFormat,
+    writer: impl Write + Send + 'static,
+) -> Box<dyn Logger + Send + Sync> {
+    match format {
+        LogFormat::Console => Box::new(ConsoleLogger::with_writer(writer)),
+        LogFormat::Json => Box::new(JsonLogger::with_writer(writer)),
+        LogFormat::Logfmt => Box::new(LogfmtLogger::with_writer(writer)),
+    }
+}
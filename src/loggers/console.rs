@@ -2,17 +2,57 @@
 //!
 //! Provides a simple console logger for development and debugging.
 
-use super::Logger;
+use super::{Logger, SinkLogger};
+use crate::format::{ColorScheme, ConsoleFormatter};
 use crate::record::LogRecord;
-use crate::utils::{format_level, format_timestamp};
+use std::io::Write;
 
 /// Adapter - Simple console logger for development
-pub struct ConsoleLogger;
+pub struct ConsoleLogger {
+    sink: SinkLogger<ConsoleFormatter, Box<dyn Write + Send>>,
+}
 
 impl ConsoleLogger {
-    /// Create a new console logger
+    /// Create a new console logger writing to stdout
     pub fn new() -> Self {
-        Self
+        Self::with_writer(std::io::stdout())
+    }
+
+    /// Create a console logger writing one record per line to `writer`
+    pub fn with_writer(writer: impl Write + Send + 'static) -> Self {
+        Self {
+            sink: SinkLogger::new(ConsoleFormatter::new(), Box::new(writer)),
+        }
+    }
+
+    /// Include the `[timestamp]` prefix (on by default)
+    pub fn with_timestamp(mut self, enabled: bool) -> Self {
+        self.sink.formatter_mut().timestamps = enabled;
+        self
+    }
+
+    /// Color each level with the ANSI style from `scheme`
+    pub fn with_color_scheme(mut self, scheme: ColorScheme) -> Self {
+        self.sink.formatter_mut().colors = Some(scheme);
+        self
+    }
+
+    /// Render levels as single characters (`T/D/I/W/E/F`) for dense output
+    pub fn with_compact_levels(mut self, enabled: bool) -> Self {
+        self.sink.formatter_mut().compact_levels = enabled;
+        self
+    }
+
+    /// Identify this adapter and its output format version in each record (off by default)
+    pub fn with_self_identify(mut self, enabled: bool) -> Self {
+        self.sink.formatter_mut().self_identify = enabled;
+        self
+    }
+
+    /// Start each line with `prefix` and a space, e.g. `[payments]`
+    pub fn with_line_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.sink.formatter_mut().line_prefix = Some(prefix.into());
+        self
     }
 }
 
@@ -24,11 +64,14 @@ impl Default for ConsoleLogger {
 
 impl Logger for ConsoleLogger {
     fn log(&self, record: &LogRecord) {
-        println!(
-            "[{}] {}: {}",
-            format_timestamp(record.timestamp()),
-            format_level(record.level()),
-            record.message()
-        );
+        self.sink.log(record);
+    }
+
+    fn log_batch(&self, records: &[LogRecord]) {
+        self.sink.log_batch(records);
+    }
+
+    fn flush(&self) {
+        self.sink.flush();
     }
 }
@@ -0,0 +1,84 @@
+//! Context-merging logger
+//!
+//! Applies the current thread's [`LogContext`](crate::context::LogContext)
+//! to every record before forwarding it.
+
+use super::Logger;
+use crate::context::capture_context;
+use crate::diagnostics::{Diagnostics, ISSUE_CONTEXT_FIELD_CONFLICT};
+use crate::record::LogRecord;
+
+/// Adapter - Merges the current thread's context into each record
+///
+/// Values set explicitly on the record win: the context request ID is only
+/// used when the record has none, and context fields never overwrite record
+/// fields with the same key.
+pub struct ContextLogger<L: Logger> {
+    inner: L,
+    conflict_diagnostics: Option<Diagnostics>,
+}
+
+impl<L: Logger> ContextLogger<L> {
+    /// Wrap `inner`
+    pub fn new(inner: L) -> Self {
+        Self {
+            inner,
+            conflict_diagnostics: None,
+        }
+    }
+
+    /// Warn through `diagnostics` when a record field overrides a different context value
+    ///
+    /// The record's value is still used. Identical values are not reported.
+    /// Off by default.
+    pub fn with_conflict_diagnostics(mut self, diagnostics: Diagnostics) -> Self {
+        self.conflict_diagnostics = Some(diagnostics);
+        self
+    }
+
+    /// Get the wrapped logger
+    pub fn inner(&self) -> &L {
+        &self.inner
+    }
+}
+
+impl<L: Logger> Logger for ContextLogger<L> {
+    fn log(&self, record: &LogRecord) {
+        let context = capture_context();
+        if context.is_empty() {
+            self.inner.log(record);
+            return;
+        }
+        let mut record = record.clone();
+        if record.request_id().is_none() {
+            if let Some(request_id) = context.request_id() {
+                record = record.with_request_id(request_id.to_string());
+            }
+        }
+        for (key, value) in context.fields() {
+            match record.fields().get(key) {
+                None => record.add_field(key.clone(), value.clone()),
+                Some(explicit) if explicit != value => {
+                    if let Some(diagnostics) = &self.conflict_diagnostics {
+                        diagnostics.report(
+                            ISSUE_CONTEXT_FIELD_CONFLICT,
+                            format_args!(
+                                "field `{key}`: record value {explicit} overrides context value {value}"
+                            ),
+                        );
+                    }
+                }
+                Some(_) => {}
+            }
+        }
+        self.inner.log(&record);
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+
+    fn barrier(&self) {
+        self.inner.barrier();
+    }
+}
@@ -0,0 +1,61 @@
+//! Deduplicating logger
+//!
+//! Suppresses a record identical to the one forwarded just before it, which
+//! collapses tight retry loops into a single line.
+
+use super::Logger;
+use crate::record::{LogLevel, LogRecord};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+type RecordKey = (LogLevel, String, HashMap<String, Value>);
+
+/// Adapter - Drops records identical to the previous one
+///
+/// Records are compared on level, message, and fields; timestamps and
+/// request IDs are ignored.
+pub struct DedupLogger<L: Logger> {
+    inner: L,
+    last: Mutex<Option<RecordKey>>,
+}
+
+impl<L: Logger> DedupLogger<L> {
+    /// Wrap `inner`, dropping consecutive duplicates
+    pub fn new(inner: L) -> Self {
+        Self {
+            inner,
+            last: Mutex::new(None),
+        }
+    }
+
+    /// Get the wrapped logger
+    pub fn inner(&self) -> &L {
+        &self.inner
+    }
+}
+
+impl<L: Logger> Logger for DedupLogger<L> {
+    fn log(&self, record: &LogRecord) {
+        let key = (
+            record.level(),
+            record.message().to_string(),
+            record.fields().clone(),
+        );
+        let mut last = self.last.lock().unwrap_or_else(|e| e.into_inner());
+        if last.as_ref() == Some(&key) {
+            return;
+        }
+        *last = Some(key);
+        drop(last);
+        self.inner.log(record);
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+
+    fn barrier(&self) {
+        self.inner.barrier();
+    }
+}
@@ -0,0 +1,84 @@
+//! Runtime-adjustable level filter
+//!
+//! A level filter whose threshold can be changed while the logger is in
+//! use, e.g. from an admin endpoint, with hooks for subsystems that need to
+//! react to the change.
+
+use super::Logger;
+use crate::record::{LogLevel, LogRecord};
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::{Arc, Mutex};
+
+type LevelCallback = Arc<dyn Fn(LogLevel) + Send + Sync>;
+
+/// Adapter - Forwards records at or above a level that can change at runtime
+///
+/// The level is held in an atomic, so checking it costs no lock. Callbacks
+/// registered with [`on_level_change`](Self::on_level_change) run
+/// synchronously, in registration order, on the thread calling
+/// [`set_level`](Self::set_level) once the new level is in effect. They run
+/// without any lock held, so a callback may itself call `set_level` or
+/// register further callbacks, which take effect from the next change.
+pub struct DynamicLevelLogger<L: Logger> {
+    inner: L,
+    min_level: AtomicU8,
+    callbacks: Mutex<Vec<LevelCallback>>,
+}
+
+impl<L: Logger> DynamicLevelLogger<L> {
+    /// Wrap `inner`, initially dropping records below `min_level`
+    pub fn new(inner: L, min_level: LogLevel) -> Self {
+        Self {
+            inner,
+            min_level: AtomicU8::new(min_level as u8),
+            callbacks: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Get the current minimum level
+    pub fn level(&self) -> LogLevel {
+        LogLevel::all()[usize::from(self.min_level.load(Ordering::Relaxed))]
+    }
+
+    /// Change the minimum level, then notify the registered callbacks
+    pub fn set_level(&self, level: LogLevel) {
+        self.min_level.store(level as u8, Ordering::Relaxed);
+        let callbacks = self
+            .callbacks
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone();
+        for callback in &callbacks {
+            callback(level);
+        }
+    }
+
+    /// Register `callback` to be called with the new level on each `set_level`
+    pub fn on_level_change(&self, callback: impl Fn(LogLevel) + Send + Sync + 'static) {
+        self.callbacks
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(Arc::new(callback));
+    }
+
+    /// Get the wrapped logger
+    pub fn inner(&self) -> &L {
+        &self.inner
+    }
+}
+
+impl<L: Logger> Logger for DynamicLevelLogger<L> {
+    fn log(&self, record: &LogRecord) {
+        if record.level() >= self.level() {
+            self.inner.log(record);
+        }
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+
+    fn barrier(&self) {
+        self.inner.barrier();
+    }
+}
@@ -0,0 +1,85 @@
+//! Enriching logger
+//!
+//! Adds a fixed set of fields (service name, region, version, ...) to every
+//! record, and optionally a snapshot of runtime metrics.
+
+use super::Logger;
+use crate::record::LogRecord;
+use crate::utils::process_thread_count;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Adapter - Adds static fields to every record
+///
+/// Fields already present on a record are kept; enrichment never overwrites
+/// an explicit value.
+pub struct EnrichingLogger<L: Logger> {
+    inner: L,
+    fields: HashMap<String, Value>,
+    runtime_metrics: bool,
+}
+
+impl<L: Logger> EnrichingLogger<L> {
+    /// Wrap `inner`, adding `fields` to each record
+    pub fn new<K: Into<String>>(inner: L, fields: impl IntoIterator<Item = (K, Value)>) -> Self {
+        Self {
+            inner,
+            fields: fields.into_iter().map(|(k, v)| (k.into(), v)).collect(),
+            runtime_metrics: false,
+        }
+    }
+
+    /// Also add runtime metrics to each record (off by default)
+    ///
+    /// Adds `threads`, the process thread count, where the platform reports
+    /// it, and with the `tokio` feature `tokio_tasks`, the alive task count
+    /// of the Tokio runtime the record is logged from. Sampling these costs
+    /// a file read per record, so enable it only while diagnosing resource
+    /// exhaustion.
+    pub fn with_runtime_metrics(mut self, enabled: bool) -> Self {
+        self.runtime_metrics = enabled;
+        self
+    }
+
+    /// Get the wrapped logger
+    pub fn inner(&self) -> &L {
+        &self.inner
+    }
+}
+
+impl<L: Logger> Logger for EnrichingLogger<L> {
+    fn log(&self, record: &LogRecord) {
+        let mut record = record.clone();
+        for (key, value) in &self.fields {
+            if !record.fields().contains_key(key) {
+                record.add_field(key.clone(), value.clone());
+            }
+        }
+        if self.runtime_metrics {
+            let explicit_threads = record.fields().contains_key("threads");
+            if let Some(threads) = (!explicit_threads).then(process_thread_count).flatten() {
+                record
+                    .fields_mut()
+                    .insert("threads".to_string(), serde_json::json!(threads));
+            }
+            #[cfg(feature = "tokio")]
+            if !record.fields().contains_key("tokio_tasks") {
+                if let Ok(handle) = tokio::runtime::Handle::try_current() {
+                    record.fields_mut().insert(
+                        "tokio_tasks".to_string(),
+                        serde_json::json!(handle.metrics().num_alive_tasks()),
+                    );
+                }
+            }
+        }
+        self.inner.log(&record);
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+
+    fn barrier(&self) {
+        self.inner.barrier();
+    }
+}
@@ -0,0 +1,84 @@
+//! Escalating logger
+//!
+//! Promotes a warning that keeps repeating to an error so it reaches paging
+//! and alerting rules that only watch `Error` records.
+
+use super::Logger;
+use crate::record::{LogLevel, LogRecord};
+use crate::utils::format_level;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Adapter - Escalates warnings repeated more than `threshold` times within `window`
+///
+/// Occurrences are counted per message over a sliding window. Once a
+/// message's count exceeds the threshold, further occurrences are forwarded
+/// at `Error` with an `escalated_from` field naming the original level.
+pub struct EscalatingLogger<L: Logger> {
+    inner: L,
+    threshold: usize,
+    window: Duration,
+    occurrences: Mutex<HashMap<String, VecDeque<Instant>>>,
+}
+
+impl<L: Logger> EscalatingLogger<L> {
+    /// Wrap `inner`, escalating after `threshold` occurrences within `window`
+    pub fn new(inner: L, threshold: usize, window: Duration) -> Self {
+        Self {
+            inner,
+            threshold,
+            window,
+            occurrences: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Get the wrapped logger
+    pub fn inner(&self) -> &L {
+        &self.inner
+    }
+
+    fn count_occurrence(&self, message: &str) -> usize {
+        let now = Instant::now();
+        let mut occurrences = self.occurrences.lock().unwrap_or_else(|e| e.into_inner());
+        occurrences.retain(|_, seen| {
+            while seen
+                .front()
+                .is_some_and(|first| now.duration_since(*first) > self.window)
+            {
+                seen.pop_front();
+            }
+            !seen.is_empty()
+        });
+        let seen = occurrences.entry(message.to_string()).or_default();
+        seen.push_back(now);
+        seen.len()
+    }
+}
+
+impl<L: Logger> Logger for EscalatingLogger<L> {
+    fn log(&self, record: &LogRecord) {
+        if record.level() != LogLevel::Warn {
+            self.inner.log(record);
+            return;
+        }
+        if self.count_occurrence(record.message()) > self.threshold {
+            let mut escalated = record.clone_with_level(LogLevel::Error);
+            escalated.fields_mut().insert(
+                "escalated_from".to_string(),
+                serde_json::json!(format_level(record.level())),
+            );
+            self.inner.log(&escalated);
+        } else {
+            self.inner.log(record);
+        }
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+
+    fn barrier(&self) {
+        self.inner.barrier();
+    }
+}
@@ -0,0 +1,70 @@
+//! Field-namespacing logger
+//!
+//! Prefixes every field key with a subsystem namespace, so `duration` from
+//! the database layer and `duration` from the HTTP layer don't collide.
+
+use super::Logger;
+use crate::fields::RESERVED_FIELD_KEYS;
+use crate::record::LogRecord;
+
+/// Adapter - Prepends a namespace to each record's field keys
+///
+/// With the namespace `db`, a `duration` field is forwarded as
+/// `db.duration`. Keys in [`RESERVED_FIELD_KEYS`] keep their names, since
+/// formatters and other adapters look them up as-is.
+pub struct FieldPrefixLogger<L: Logger> {
+    inner: L,
+    prefix: String,
+}
+
+impl<L: Logger> FieldPrefixLogger<L> {
+    /// Wrap `inner`, namespacing field keys under `prefix`
+    ///
+    /// A `.` separator is added unless `prefix` already ends with one.
+    pub fn new(inner: L, prefix: &str) -> Self {
+        let prefix = if prefix.ends_with('.') {
+            prefix.to_string()
+        } else {
+            format!("{prefix}.")
+        };
+        Self { inner, prefix }
+    }
+
+    /// Get the prefix prepended to field keys, including the separator
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// Get the wrapped logger
+    pub fn inner(&self) -> &L {
+        &self.inner
+    }
+}
+
+impl<L: Logger> Logger for FieldPrefixLogger<L> {
+    fn log(&self, record: &LogRecord) {
+        if record.fields().is_empty() {
+            self.inner.log(record);
+            return;
+        }
+        let mut record = record.clone();
+        let fields = std::mem::take(record.fields_mut());
+        for (key, value) in fields {
+            let key = if RESERVED_FIELD_KEYS.contains(&key.as_str()) {
+                key
+            } else {
+                format!("{}{key}", self.prefix)
+            };
+            record.fields_mut().insert(key, value);
+        }
+        self.inner.log(&record);
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+
+    fn barrier(&self) {
+        self.inner.barrier();
+    }
+}
@@ -0,0 +1,57 @@
+//! Field-transforming logger
+//!
+//! Applies a [`FieldSerializers`] registry to each record before it reaches
+//! the inner logger.
+
+use super::Logger;
+use crate::fields::FieldSerializers;
+use crate::record::LogRecord;
+
+/// Adapter - Rewrites registered fields through their transforms
+///
+/// ```rust
+/// use tyl_logging::{FieldSerializers, FieldSerializingLogger, JsonLogger};
+///
+/// let serializers = FieldSerializers::new().with("latency", |value| {
+///     let secs = value["secs"].as_f64().unwrap_or(0.0);
+///     let nanos = value["nanos"].as_f64().unwrap_or(0.0);
+///     serde_json::json!(secs * 1000.0 + nanos / 1_000_000.0)
+/// });
+/// let logger = FieldSerializingLogger::new(JsonLogger::new(), serializers);
+/// ```
+pub struct FieldSerializingLogger<L: Logger> {
+    inner: L,
+    serializers: FieldSerializers,
+}
+
+impl<L: Logger> FieldSerializingLogger<L> {
+    /// Wrap `inner`, transforming fields with `serializers`
+    pub fn new(inner: L, serializers: FieldSerializers) -> Self {
+        Self { inner, serializers }
+    }
+
+    /// Get the wrapped logger
+    pub fn inner(&self) -> &L {
+        &self.inner
+    }
+}
+
+impl<L: Logger> Logger for FieldSerializingLogger<L> {
+    fn log(&self, record: &LogRecord) {
+        if !self.serializers.applies_to(record) {
+            self.inner.log(record);
+            return;
+        }
+        let mut record = record.clone();
+        self.serializers.apply(&mut record);
+        self.inner.log(&record);
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+
+    fn barrier(&self) {
+        self.inner.barrier();
+    }
+}
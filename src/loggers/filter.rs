@@ -0,0 +1,45 @@
+//! Level filtering logger
+//!
+//! Drops records below a minimum severity before they reach the inner logger.
+
+use super::Logger;
+use crate::record::{LogLevel, LogRecord};
+
+/// Adapter - Forwards only records at or above `min_level`
+pub struct LevelFilterLogger<L: Logger> {
+    inner: L,
+    min_level: LogLevel,
+}
+
+impl<L: Logger> LevelFilterLogger<L> {
+    /// Wrap `inner`, dropping records below `min_level`
+    pub fn new(inner: L, min_level: LogLevel) -> Self {
+        Self { inner, min_level }
+    }
+
+    /// Get the minimum level forwarded
+    pub fn min_level(&self) -> LogLevel {
+        self.min_level
+    }
+
+    /// Get the wrapped logger
+    pub fn inner(&self) -> &L {
+        &self.inner
+    }
+}
+
+impl<L: Logger> Logger for LevelFilterLogger<L> {
+    fn log(&self, record: &LogRecord) {
+        if record.level() >= self.min_level {
+            self.inner.log(record);
+        }
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+
+    fn barrier(&self) {
+        self.inner.barrier();
+    }
+}
@@ -0,0 +1,197 @@
+//! HTTP batch logger
+//!
+//! Ships records to a managed log service's HTTP intake (Loki, Datadog, and
+//! similar) as JSON array POSTs.
+
+use super::Logger;
+use crate::diagnostics::{Diagnostics, ISSUE_DROPPED_RECORDS};
+use crate::format::{json_array, Formatter, JsonFormatter};
+use crate::record::LogRecord;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Batch size used by [`HttpLogger::new`]
+pub const DEFAULT_HTTP_BATCH_SIZE: usize = 100;
+
+/// Flush interval used by [`HttpLogger::new`]
+pub const DEFAULT_HTTP_FLUSH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Retry count used by [`HttpLogger::new`]
+pub const DEFAULT_HTTP_MAX_RETRIES: u32 = 3;
+
+struct Pending {
+    records: Vec<String>,
+    last_flush: Instant,
+}
+
+/// Adapter - POSTs buffered records as a JSON array to an HTTP endpoint
+///
+/// A batch is sent once it reaches the maximum batch size, when a record
+/// arrives after the flush interval has elapsed, on [`Logger::flush`], and
+/// on drop. There is no background timer, so a quiet logger holds its batch
+/// until the next record or an explicit flush.
+///
+/// Sending happens on the logging thread. A failed POST (transport error or
+/// non-2xx status) is retried up to the configured count with linear
+/// backoff; if every attempt fails the batch is dropped and counted in
+/// [`dropped`](Self::dropped) so a dead endpoint cannot grow memory, and a
+/// throttled diagnostic is reported.
+///
+/// Each record is rendered on its own as it arrives, so a record that fails
+/// to serialize is replaced by the formatter's fallback object rather than
+/// emptying the request body.
+pub struct HttpLogger {
+    url: String,
+    headers: Vec<(String, String)>,
+    max_batch_size: usize,
+    flush_interval: Duration,
+    max_retries: u32,
+    retry_backoff: Duration,
+    agent: ureq::Agent,
+    formatter: JsonFormatter,
+    pending: Mutex<Pending>,
+    dropped: AtomicU64,
+    diagnostics: Diagnostics,
+}
+
+impl HttpLogger {
+    /// Create a logger posting batches to `url`
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            headers: Vec::new(),
+            max_batch_size: DEFAULT_HTTP_BATCH_SIZE,
+            flush_interval: DEFAULT_HTTP_FLUSH_INTERVAL,
+            max_retries: DEFAULT_HTTP_MAX_RETRIES,
+            retry_backoff: Duration::from_millis(100),
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(10))
+                .build(),
+            formatter: JsonFormatter::new(),
+            pending: Mutex::new(Pending {
+                records: Vec::new(),
+                last_flush: Instant::now(),
+            }),
+            dropped: AtomicU64::new(0),
+            diagnostics: Diagnostics::new(),
+        }
+    }
+
+    /// Send `name: value` with every request, e.g. an API key
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Send a batch once `size` records are buffered (minimum 1)
+    pub fn with_max_batch_size(mut self, size: usize) -> Self {
+        self.max_batch_size = size.max(1);
+        self
+    }
+
+    /// Send the pending batch when a record arrives `interval` after the last send
+    pub fn with_flush_interval(mut self, interval: Duration) -> Self {
+        self.flush_interval = interval;
+        self
+    }
+
+    /// Retry a failed POST up to `retries` times before dropping the batch
+    pub fn with_max_retries(mut self, retries: u32) -> Self {
+        self.max_retries = retries;
+        self
+    }
+
+    /// Wait `backoff` times the attempt number between retries
+    pub fn with_retry_backoff(mut self, backoff: Duration) -> Self {
+        self.retry_backoff = backoff;
+        self
+    }
+
+    /// Give up on a single request after `timeout`
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.agent = ureq::AgentBuilder::new().timeout(timeout).build();
+        self
+    }
+
+    /// Report dropped batches through `diagnostics` instead of stderr
+    pub fn with_diagnostics(mut self, diagnostics: Diagnostics) -> Self {
+        self.diagnostics = diagnostics;
+        self
+    }
+
+    /// Get the endpoint URL
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Number of records dropped after exhausting retries
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    fn take_batch(&self, force: bool) -> Option<Vec<String>> {
+        let mut pending = self.pending.lock().unwrap_or_else(|e| e.into_inner());
+        let due = force
+            || pending.records.len() >= self.max_batch_size
+            || pending.last_flush.elapsed() >= self.flush_interval;
+        if !due || pending.records.is_empty() {
+            return None;
+        }
+        pending.last_flush = Instant::now();
+        Some(std::mem::take(&mut pending.records))
+    }
+
+    fn send(&self, batch: Vec<String>) {
+        let count = batch.len() as u64;
+        let body = json_array(&batch);
+        for attempt in 0..=self.max_retries {
+            if attempt > 0 {
+                std::thread::sleep(self.retry_backoff * attempt);
+            }
+            let mut request = self
+                .agent
+                .post(&self.url)
+                .set("Content-Type", "application/json");
+            for (name, value) in &self.headers {
+                request = request.set(name, value);
+            }
+            if request.send_string(&body).is_ok() {
+                return;
+            }
+        }
+        self.dropped.fetch_add(count, Ordering::Relaxed);
+        self.diagnostics.report(
+            ISSUE_DROPPED_RECORDS,
+            format_args!(
+                "{count} records dropped after failing to reach {}",
+                self.url
+            ),
+        );
+    }
+}
+
+impl Logger for HttpLogger {
+    fn log(&self, record: &LogRecord) {
+        self.pending
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .records
+            .push(self.formatter.format_string(record));
+        if let Some(batch) = self.take_batch(false) {
+            self.send(batch);
+        }
+    }
+
+    fn flush(&self) {
+        if let Some(batch) = self.take_batch(true) {
+            self.send(batch);
+        }
+    }
+}
+
+impl Drop for HttpLogger {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
@@ -2,17 +2,135 @@
 //!
 //! Provides structured JSON logging for production environments.
 
-use super::Logger;
+use super::{Logger, SinkLogger};
+use crate::format::JsonFormatter;
+pub use crate::format::LineEnding;
 use crate::record::LogRecord;
-use crate::utils::format_level;
+use std::io::Write;
 
 /// Adapter - JSON structured logger for production
-pub struct JsonLogger;
+pub struct JsonLogger {
+    sink: SinkLogger<JsonFormatter, Box<dyn Write + Send>>,
+}
 
 impl JsonLogger {
-    /// Create a new JSON logger
+    /// Create a new JSON logger writing to stdout
     pub fn new() -> Self {
-        Self
+        Self::with_writer(std::io::stdout())
+    }
+
+    /// Create a JSON logger writing one record per line to `writer`
+    pub fn with_writer(writer: impl Write + Send + 'static) -> Self {
+        Self {
+            sink: SinkLogger::new(JsonFormatter::new(), Box::new(writer)),
+        }
+    }
+
+    /// Set the terminator written after each record (defaults to `\n`)
+    pub fn with_line_ending(mut self, line_ending: LineEnding) -> Self {
+        self.sink = self.sink.with_line_ending(line_ending);
+        self
+    }
+
+    /// Emit levels as severity-sortable strings such as `"2_INFO"`
+    pub fn with_sortable_levels(mut self, enabled: bool) -> Self {
+        self.sink.formatter_mut().sortable_levels = enabled;
+        self
+    }
+
+    /// Nest custom fields under `name` instead of `"fields"`
+    ///
+    /// The other top-level keys are unaffected, so choosing `name` equal to
+    /// one of them (e.g. `"message"`) replaces that key.
+    pub fn with_fields_key(mut self, name: &str) -> Self {
+        self.sink.formatter_mut().fields_key = name.to_string();
+        self
+    }
+
+    /// Include the `"timestamp"` key (on by default)
+    ///
+    /// Disable when the log shipper stamps ingestion time and host clocks
+    /// are not trusted.
+    pub fn with_timestamp(mut self, enabled: bool) -> Self {
+        self.sink.formatter_mut().timestamps = enabled;
+        self
+    }
+
+    /// Add a `"_size_bytes"` field with the byte length of each line (off by default)
+    ///
+    /// The value is exact: it counts the whole serialized line, including
+    /// the `_size_bytes` field itself but not the line terminator. Because
+    /// the size is part of what it measures, the record is serialized
+    /// repeatedly until the length stops changing, which costs two or three
+    /// extra serializations per record.
+    pub fn with_size_field(mut self, enabled: bool) -> Self {
+        self.sink.formatter_mut().size_field = enabled;
+        self
+    }
+
+    /// Leave out the `"message"` key for records with an empty message
+    ///
+    /// Off by default, so field-only records carry `"message": ""`.
+    pub fn with_omit_empty_message(mut self, enabled: bool) -> Self {
+        self.sink.formatter_mut().omit_empty_message = enabled;
+        self
+    }
+
+    /// Limit how deeply field values are nested in the output (defaults to 32)
+    ///
+    /// Arrays and objects more than `depth` levels inside a field value are
+    /// replaced with `"[max depth exceeded]"`, so deeply recursive values
+    /// from untrusted input cannot overflow the stack while serializing.
+    pub fn with_max_depth(mut self, depth: usize) -> Self {
+        self.sink.formatter_mut().max_depth = depth;
+        self
+    }
+
+    /// Emit both an epoch and a human-readable timestamp (off by default)
+    ///
+    /// `"timestamp"` becomes epoch milliseconds, and `"timestamp_iso"` holds
+    /// the same instant as an RFC 3339 UTC string. Has no effect when
+    /// timestamps are disabled with [`with_timestamp`](Self::with_timestamp).
+    pub fn with_dual_timestamp(mut self, enabled: bool) -> Self {
+        self.sink.formatter_mut().dual_timestamp = enabled;
+        self
+    }
+
+    /// Emit the level as `{"name": "WARN", "value": 3}` instead of a string
+    ///
+    /// `value` is the level's numeric severity, so consumers can filter on
+    /// it without a name lookup.
+    pub fn with_object_level(mut self, enabled: bool) -> Self {
+        self.sink.formatter_mut().object_level = enabled;
+        self
+    }
+
+    /// Identify this adapter and its output format version in each record (off by default)
+    pub fn with_self_identify(mut self, enabled: bool) -> Self {
+        self.sink.formatter_mut().self_identify = enabled;
+        self
+    }
+
+    /// Add a `"prefix"` key holding `prefix`, e.g. `[payments]`, to each record
+    pub fn with_line_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.sink.formatter_mut().line_prefix = Some(prefix.into());
+        self
+    }
+
+    /// Create a JSON logger writing to an inherited file descriptor
+    ///
+    /// Useful for sidecars that read from a fixed descriptor (e.g. fd 3)
+    /// rather than stdout.
+    ///
+    /// # Safety
+    ///
+    /// `fd` must be an open descriptor that nothing else owns. The logger
+    /// takes ownership and closes it when dropped, so the caller must not
+    /// use or close it afterwards.
+    #[cfg(unix)]
+    pub unsafe fn from_raw_fd(fd: std::os::unix::io::RawFd) -> Self {
+        use std::os::unix::io::FromRawFd;
+        Self::with_writer(std::fs::File::from_raw_fd(fd))
     }
 }
 
@@ -24,13 +142,14 @@ impl Default for JsonLogger {
 
 impl Logger for JsonLogger {
     fn log(&self, record: &LogRecord) {
-        let json_record = serde_json::json!({
-            "timestamp": record.timestamp(),
-            "level": format_level(record.level()),
-            "message": record.message(),
-            "fields": record.fields(),
-            "request_id": record.request_id()
-        });
-        println!("{json_record}");
+        self.sink.log(record);
+    }
+
+    fn log_batch(&self, records: &[LogRecord]) {
+        self.sink.log_batch(records);
+    }
+
+    fn flush(&self) {
+        self.sink.flush();
     }
 }
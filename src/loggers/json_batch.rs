@@ -0,0 +1,120 @@
+//! Batched JSON array logger
+//!
+//! Buffers records and writes them as a single JSON array, the shape most
+//! HTTP bulk ingestion endpoints expect instead of newline-delimited JSON.
+
+use super::Logger;
+use crate::diagnostics::{Diagnostics, ISSUE_SINK_WRITE_FAILED};
+use crate::format::{json_array, Formatter, JsonFormatter};
+use crate::record::LogRecord;
+use std::io::Write;
+use std::sync::Mutex;
+
+/// Batch size used by [`JsonBatchLogger::new`]
+pub const DEFAULT_MAX_BATCH_SIZE: usize = 100;
+
+struct BatchState {
+    writer: Box<dyn Write + Send>,
+    pending: Vec<String>,
+}
+
+/// Adapter - Writes buffered records as one `[{...},{...}]` JSON array
+///
+/// Records are rendered with [`JsonFormatter`] as they arrive and written on
+/// [`Logger::flush`], when the buffer reaches the maximum batch size,
+/// or when the logger is dropped. Each array is followed by a newline.
+///
+/// Every array element is the line the formatter would write for that
+/// record on its own, so all formatter options apply; a `_size_bytes` field
+/// measures the record's own object. A record that fails to serialize is
+/// replaced by the formatter's fallback object, and write failures are
+/// reported as throttled diagnostics.
+pub struct JsonBatchLogger {
+    formatter: JsonFormatter,
+    max_batch_size: usize,
+    state: Mutex<BatchState>,
+    diagnostics: Diagnostics,
+}
+
+impl JsonBatchLogger {
+    /// Create a batch logger writing arrays to `writer`
+    pub fn new(writer: impl Write + Send + 'static) -> Self {
+        Self {
+            formatter: JsonFormatter::new(),
+            max_batch_size: DEFAULT_MAX_BATCH_SIZE,
+            state: Mutex::new(BatchState {
+                writer: Box::new(writer),
+                pending: Vec::new(),
+            }),
+            diagnostics: Diagnostics::new(),
+        }
+    }
+
+    /// Flush automatically once `size` records are buffered (minimum 1)
+    pub fn with_max_batch_size(mut self, size: usize) -> Self {
+        self.max_batch_size = size.max(1);
+        self
+    }
+
+    /// Use `formatter` to render each record
+    pub fn with_formatter(mut self, formatter: JsonFormatter) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Report write failures through `diagnostics` instead of stderr
+    pub fn with_diagnostics(mut self, diagnostics: Diagnostics) -> Self {
+        self.diagnostics = diagnostics;
+        self
+    }
+
+    /// Number of records waiting for the next flush
+    pub fn pending(&self) -> usize {
+        self.state
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .pending
+            .len()
+    }
+
+    fn write_batch(state: &mut BatchState, diagnostics: &Diagnostics) {
+        if state.pending.is_empty() {
+            return;
+        }
+        let batch = std::mem::take(&mut state.pending);
+        let mut line = json_array(&batch);
+        line.push('\n');
+        let written = state
+            .writer
+            .write_all(line.as_bytes())
+            .and_then(|()| state.writer.flush());
+        if let Err(err) = written {
+            diagnostics.report(ISSUE_SINK_WRITE_FAILED, err);
+        }
+    }
+}
+
+impl Logger for JsonBatchLogger {
+    fn log(&self, record: &LogRecord) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.pending.push(self.formatter.format_string(record));
+        if state.pending.len() >= self.max_batch_size {
+            Self::write_batch(&mut state, &self.diagnostics);
+        }
+    }
+
+    /// Write all buffered records as one JSON array
+    ///
+    /// Does nothing when the buffer is empty.
+    fn flush(&self) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        Self::write_batch(&mut state, &self.diagnostics);
+    }
+}
+
+impl Drop for JsonBatchLogger {
+    fn drop(&mut self) {
+        let state = self.state.get_mut().unwrap_or_else(|e| e.into_inner());
+        Self::write_batch(state, &self.diagnostics);
+    }
+}
@@ -0,0 +1,78 @@
+//! Level-counting logger
+//!
+//! Counts records per level instead of writing them, for assertions such as
+//! "exactly three errors were logged" in tests, or as a cheap metrics tap
+//! alongside a real logger in a [`MultiLogger`](super::MultiLogger).
+
+use super::Logger;
+use crate::record::{LogLevel, LogRecord};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Adapter - Counts the records it receives per level
+///
+/// Counters are atomic, so one counter can be shared across threads behind
+/// an `Arc`.
+#[derive(Debug, Default)]
+pub struct LevelCounter {
+    counts: [AtomicU64; 6],
+}
+
+impl LevelCounter {
+    /// Create a counter with every level at zero
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of records logged at `level`
+    pub fn count(&self, level: LogLevel) -> u64 {
+        self.counts[level as usize].load(Ordering::Relaxed)
+    }
+
+    /// Number of records logged at any level
+    pub fn total(&self) -> u64 {
+        LogLevel::all().iter().map(|&level| self.count(level)).sum()
+    }
+
+    /// Number of `Trace` records
+    pub fn traces(&self) -> u64 {
+        self.count(LogLevel::Trace)
+    }
+
+    /// Number of `Debug` records
+    pub fn debugs(&self) -> u64 {
+        self.count(LogLevel::Debug)
+    }
+
+    /// Number of `Info` records
+    pub fn infos(&self) -> u64 {
+        self.count(LogLevel::Info)
+    }
+
+    /// Number of `Warn` records
+    pub fn warns(&self) -> u64 {
+        self.count(LogLevel::Warn)
+    }
+
+    /// Number of `Error` records
+    pub fn errors(&self) -> u64 {
+        self.count(LogLevel::Error)
+    }
+
+    /// Number of `Fatal` records
+    pub fn fatals(&self) -> u64 {
+        self.count(LogLevel::Fatal)
+    }
+
+    /// Set every count back to zero
+    pub fn reset(&self) {
+        for count in &self.counts {
+            count.store(0, Ordering::Relaxed);
+        }
+    }
+}
+
+impl Logger for LevelCounter {
+    fn log(&self, record: &LogRecord) {
+        self.counts[record.level() as usize].fetch_add(1, Ordering::Relaxed);
+    }
+}
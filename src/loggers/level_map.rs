@@ -0,0 +1,65 @@
+//! Level-mapping logger
+//!
+//! Recomputes each record's level from its contents, e.g. promoting a
+//! request log to `Error` when its status code is a server error.
+
+use super::Logger;
+use crate::record::{LogLevel, LogRecord};
+
+type LevelFn = Box<dyn Fn(&LogRecord) -> LogLevel + Send + Sync>;
+
+/// Adapter - Forwards each record at the level chosen by a mapping function
+///
+/// The function sees the record as logged and returns its effective level;
+/// returning `record.level()` leaves the record untouched.
+///
+/// ```rust
+/// use tyl_logging::{JsonLogger, LevelMapLogger, LogLevel};
+///
+/// let logger = LevelMapLogger::new(JsonLogger::new(), |record| {
+///     match record.fields().get("status").and_then(|s| s.as_u64()) {
+///         Some(status) if status >= 500 => LogLevel::Error,
+///         _ => record.level(),
+///     }
+/// });
+/// ```
+pub struct LevelMapLogger<L: Logger> {
+    inner: L,
+    map: LevelFn,
+}
+
+impl<L: Logger> LevelMapLogger<L> {
+    /// Wrap `inner`, mapping each record's level through `map`
+    pub fn new(inner: L, map: impl Fn(&LogRecord) -> LogLevel + Send + Sync + 'static) -> Self {
+        Self {
+            inner,
+            map: Box::new(map),
+        }
+    }
+
+    /// Get the wrapped logger
+    pub fn inner(&self) -> &L {
+        &self.inner
+    }
+}
+
+impl<L: Logger> Logger for LevelMapLogger<L> {
+    fn log(&self, record: &LogRecord) {
+        let level = (self.map)(record);
+        if level == record.level() {
+            self.inner.log(record);
+            return;
+        }
+        let mut record = record.clone();
+        record.set_level(level);
+        self.inner.log(&record);
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+
+    fn barrier(&self) {
+        self.inner.barrier();
+    }
+}
@@ -0,0 +1,53 @@
+//! Logfmt logger implementation
+//!
+//! Provides `key=value` line output understood by many log shippers.
+
+use super::{Logger, SinkLogger};
+use crate::format::LogfmtFormatter;
+use crate::record::LogRecord;
+use std::io::Write;
+
+/// Adapter - logfmt (`key=value`) logger
+pub struct LogfmtLogger {
+    sink: SinkLogger<LogfmtFormatter, Box<dyn Write + Send>>,
+}
+
+impl LogfmtLogger {
+    /// Create a new logfmt logger writing to stdout
+    pub fn new() -> Self {
+        Self::with_writer(std::io::stdout())
+    }
+
+    /// Create a logfmt logger writing one record per line to `writer`
+    pub fn with_writer(writer: impl Write + Send + 'static) -> Self {
+        Self {
+            sink: SinkLogger::new(LogfmtFormatter::new(), Box::new(writer)),
+        }
+    }
+
+    /// Identify this adapter and its output format version in each record (off by default)
+    pub fn with_self_identify(mut self, enabled: bool) -> Self {
+        self.sink.formatter_mut().self_identify = enabled;
+        self
+    }
+}
+
+impl Default for LogfmtLogger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Logger for LogfmtLogger {
+    fn log(&self, record: &LogRecord) {
+        self.sink.log(record);
+    }
+
+    fn log_batch(&self, records: &[LogRecord]) {
+        self.sink.log_batch(records);
+    }
+
+    fn flush(&self) {
+        self.sink.flush();
+    }
+}
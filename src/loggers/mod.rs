@@ -3,17 +3,269 @@
 //! This module contains different logger implementations that can output
 //! log records in various formats.
 
-use crate::record::LogRecord;
+use crate::record::{LogLevel, LogRecord};
+use crate::timing::TimedScope;
+use serde_json::Value;
+use std::sync::Arc;
 
 /// Port - Main logging interface that all loggers must implement
 pub trait Logger {
     /// Log a record to the output destination
     fn log(&self, record: &LogRecord);
+
+    /// Log several records in order
+    ///
+    /// The default implementation calls [`Logger::log`] for each record.
+    /// IO-backed loggers override it to acquire their output lock once for
+    /// the whole batch.
+    fn log_batch(&self, records: &[LogRecord]) {
+        for record in records {
+            self.log(record);
+        }
+    }
+
+    /// Flush any buffered output
+    ///
+    /// The default implementation does nothing. Loggers that buffer or
+    /// write to an IO destination flush it, and wrapping loggers forward
+    /// the call to the logger they wrap.
+    fn flush(&self) {}
+
+    /// Block until every record submitted so far has been fully handled
+    ///
+    /// A deterministic sync point, e.g. before a test asserts on output.
+    /// The default implementation calls [`Logger::flush`], which is all a
+    /// logger that writes synchronously needs; loggers that hand records to
+    /// another thread override it to wait for that work to drain, and
+    /// wrapping loggers forward it to the logger they wrap.
+    fn barrier(&self) {
+        self.flush();
+    }
+}
+
+impl<L: Logger + ?Sized> Logger for &L {
+    fn log(&self, record: &LogRecord) {
+        (**self).log(record);
+    }
+
+    fn log_batch(&self, records: &[LogRecord]) {
+        (**self).log_batch(records);
+    }
+
+    fn flush(&self) {
+        (**self).flush();
+    }
+
+    fn barrier(&self) {
+        (**self).barrier();
+    }
+}
+
+impl<L: Logger + ?Sized> Logger for Box<L> {
+    fn log(&self, record: &LogRecord) {
+        (**self).log(record);
+    }
+
+    fn log_batch(&self, records: &[LogRecord]) {
+        (**self).log_batch(records);
+    }
+
+    fn flush(&self) {
+        (**self).flush();
+    }
+
+    fn barrier(&self) {
+        (**self).barrier();
+    }
 }
 
+impl<L: Logger + ?Sized> Logger for Arc<L> {
+    fn log(&self, record: &LogRecord) {
+        (**self).log(record);
+    }
+
+    fn log_batch(&self, records: &[LogRecord]) {
+        (**self).log_batch(records);
+    }
+
+    fn flush(&self) {
+        (**self).flush();
+    }
+
+    fn barrier(&self) {
+        (**self).barrier();
+    }
+}
+
+/// Convenience methods available on every [`Logger`]
+pub trait LoggerExt: Logger {
+    /// Start a [`TimedScope`] that logs its duration when dropped
+    ///
+    /// ```rust
+    /// use tyl_logging::{ConsoleLogger, LoggerExt};
+    ///
+    /// let logger = ConsoleLogger::new();
+    /// let _span = logger.timed_scope("db.query");
+    /// // ... work ...
+    /// ```
+    fn timed_scope(&self, name: impl Into<String>) -> TimedScope<'_, Self> {
+        TimedScope::new(self, name)
+    }
+
+    /// Log `message` at a level chosen at runtime
+    ///
+    /// ```rust
+    /// use tyl_logging::{ConsoleLogger, LogLevel, LoggerExt};
+    ///
+    /// let level: LogLevel = "warn".parse().unwrap();
+    /// ConsoleLogger::new().log_at(level, "disk almost full");
+    /// ```
+    fn log_at(&self, level: LogLevel, message: impl Into<String>) {
+        self.log(&LogRecord::new(level, message));
+    }
+
+    /// Log `message` with `fields` at a level chosen at runtime
+    fn log_at_with_fields<K: Into<String>>(
+        &self,
+        level: LogLevel,
+        message: impl Into<String>,
+        fields: impl IntoIterator<Item = (K, Value)>,
+    ) {
+        let mut record = LogRecord::new(level, message);
+        for (key, value) in fields {
+            record.add_field(key, value);
+        }
+        self.log(&record);
+    }
+
+    /// Log previously captured records again, in order
+    ///
+    /// Pairs with [`capture_thread_logs`](crate::capture_thread_logs) to
+    /// render records captured in a test through a real formatter:
+    ///
+    /// ```rust
+    /// use tyl_logging::{capture_thread_logs, ConsoleLogger, JsonLogger, LogLevel, LogRecord,
+    ///     Logger, LoggerExt, ThreadCaptureLogger};
+    ///
+    /// let logger = ThreadCaptureLogger::new(JsonLogger::new());
+    /// let (_, records) = capture_thread_logs(|| {
+    ///     logger.log(&LogRecord::new(LogLevel::Warn, "retrying"));
+    /// });
+    /// ConsoleLogger::new().replay(&records);
+    /// ```
+    fn replay(&self, records: &[LogRecord]) {
+        self.log_batch(records);
+    }
+
+    /// Wrap this logger so field keys are namespaced under `prefix`
+    ///
+    /// ```rust
+    /// use tyl_logging::{JsonLogger, LoggerExt};
+    ///
+    /// // `duration` is logged as `db.duration`
+    /// let db_logger = JsonLogger::new().with_field_prefix("db");
+    /// ```
+    fn with_field_prefix(self, prefix: &str) -> field_prefix::FieldPrefixLogger<Self>
+    where
+        Self: Sized,
+    {
+        field_prefix::FieldPrefixLogger::new(self, prefix)
+    }
+}
+
+impl<L: Logger + ?Sized> LoggerExt for L {}
+
 // Re-export logger implementations
+pub mod cef;
+pub mod channel;
+pub mod circuit_breaker;
+pub mod configured;
 pub mod console;
+pub mod context;
+pub mod dedup;
+pub mod dynamic_level;
+pub mod enrich;
+pub mod escalating;
+pub mod field_prefix;
+pub mod field_serializing;
+pub mod filter;
+#[cfg(feature = "http-client")]
+pub mod http;
 pub mod json;
+pub mod json_batch;
+pub mod level_counter;
+pub mod level_map;
+pub mod logfmt;
+pub mod multi;
+#[cfg(feature = "otel")]
+pub mod otlp;
+#[cfg(feature = "regex")]
+pub mod pattern_redact;
+#[cfg(feature = "protobuf")]
+pub mod protobuf;
+pub mod quiet;
+pub mod rate_limit;
+pub mod redact;
+pub mod request_buffer;
+pub mod resource;
+#[cfg(feature = "s3")]
+pub mod s3;
+pub mod sampling;
+pub mod sharded_console;
+pub mod sink;
+#[cfg(feature = "slog")]
+pub mod slog_adapter;
+pub mod span;
+pub mod stats;
+pub mod swappable;
+pub mod tenant;
+pub mod toggle;
+pub mod trace_sampling;
+pub mod uptime;
 
+pub use cef::CefLogger;
+pub use channel::{ChannelAck, ChannelLogger};
+pub use circuit_breaker::CircuitBreakerLogger;
+pub use configured::{build_logger, build_logger_with_writer, ConfiguredLogger};
 pub use console::ConsoleLogger;
-pub use json::JsonLogger;
+pub use context::ContextLogger;
+pub use dedup::DedupLogger;
+pub use dynamic_level::DynamicLevelLogger;
+pub use enrich::EnrichingLogger;
+pub use escalating::EscalatingLogger;
+pub use field_prefix::FieldPrefixLogger;
+pub use field_serializing::FieldSerializingLogger;
+pub use filter::LevelFilterLogger;
+#[cfg(feature = "http-client")]
+pub use http::HttpLogger;
+pub use json::{JsonLogger, LineEnding};
+pub use json_batch::JsonBatchLogger;
+pub use level_counter::LevelCounter;
+pub use level_map::LevelMapLogger;
+pub use logfmt::LogfmtLogger;
+pub use multi::MultiLogger;
+#[cfg(feature = "otel")]
+pub use otlp::OtlpLogger;
+#[cfg(feature = "regex")]
+pub use pattern_redact::PatternRedactingLogger;
+#[cfg(feature = "protobuf")]
+pub use protobuf::ProtobufLogger;
+pub use quiet::QuietUntilErrorLogger;
+pub use rate_limit::RateLimitingLogger;
+pub use redact::RedactingLogger;
+pub use request_buffer::RequestBufferLogger;
+pub use resource::ResourceLogger;
+#[cfg(feature = "s3")]
+pub use s3::ObjectStoreLogger;
+pub use sampling::{SamplePriority, SamplingLogger};
+pub use sharded_console::ShardedConsoleLogger;
+pub use sink::SinkLogger;
+#[cfg(feature = "slog")]
+pub use slog_adapter::SlogAdapter;
+pub use span::{SpanGuard, SpanLogger};
+pub use stats::StatsLogger;
+pub use swappable::SwappableLogger;
+pub use tenant::{tenant_from_field, TenantKey, TenantRouter};
+pub use toggle::ToggleLogger;
+pub use trace_sampling::TraceSamplingLogger;
+pub use uptime::UptimeLogger;
@@ -0,0 +1,85 @@
+//! Fan-out logger
+//!
+//! Sends every record to several loggers, e.g. a console for humans and a
+//! file for later analysis.
+
+use super::{LevelFilterLogger, Logger};
+use crate::record::{LogLevel, LogRecord};
+
+/// Adapter - Forwards each record to every child logger in order
+///
+/// Children added with [`with_min_level`](Self::with_min_level) have their
+/// own threshold, e.g. verbose console output next to a smaller file:
+///
+/// ```rust
+/// use tyl_logging::{ConsoleLogger, JsonLogger, LogLevel, LoggerPipeline, MultiLogger};
+///
+/// # let file = std::io::sink();
+/// let logger = LoggerPipeline::new().sink(
+///     MultiLogger::new()
+///         .with_min_level(ConsoleLogger::new(), LogLevel::Trace)
+///         .with_min_level(JsonLogger::with_writer(file), LogLevel::Info),
+/// );
+/// ```
+#[derive(Default)]
+pub struct MultiLogger {
+    loggers: Vec<Box<dyn Logger + Send + Sync>>,
+}
+
+impl MultiLogger {
+    /// Create a logger with no children
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a child logger
+    pub fn with(mut self, logger: impl Logger + Send + Sync + 'static) -> Self {
+        self.loggers.push(Box::new(logger));
+        self
+    }
+
+    /// Add a child logger that only receives records at or above `min_level`
+    pub fn with_min_level(
+        self,
+        logger: impl Logger + Send + Sync + 'static,
+        min_level: LogLevel,
+    ) -> Self {
+        self.with(LevelFilterLogger::new(logger, min_level))
+    }
+
+    /// Number of child loggers
+    pub fn len(&self) -> usize {
+        self.loggers.len()
+    }
+
+    /// Check whether there are no child loggers
+    pub fn is_empty(&self) -> bool {
+        self.loggers.is_empty()
+    }
+}
+
+impl Logger for MultiLogger {
+    fn log(&self, record: &LogRecord) {
+        for logger in &self.loggers {
+            logger.log(record);
+        }
+    }
+
+    fn log_batch(&self, records: &[LogRecord]) {
+        for logger in &self.loggers {
+            logger.log_batch(records);
+        }
+    }
+
+    fn flush(&self) {
+        for logger in &self.loggers {
+            logger.flush();
+        }
+    }
+
+    fn barrier(&self) {
+        for logger in &self.loggers {
+            logger.barrier();
+        }
+    }
+}
@@ -0,0 +1,126 @@
+//! OpenTelemetry logger implementation
+//!
+//! Converts records into OpenTelemetry log records and hands them to an
+//! `opentelemetry_sdk` logger provider, whose processors and exporters
+//! (e.g. an `opentelemetry-otlp` exporter behind a batch processor) decide
+//! how and when they are shipped.
+
+use super::Logger;
+use crate::record::{LogLevel, LogRecord};
+use crate::utils::format_level;
+use opentelemetry::logs::{AnyValue, LogRecord as _, Logger as _, LoggerProvider as _, Severity};
+use opentelemetry::{Key, SpanId, TraceId};
+use opentelemetry_sdk::logs::{SdkLogger, SdkLoggerProvider};
+use serde_json::Value;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Instrumentation scope name reported for records from this crate
+pub const OTEL_SCOPE: &str = "tyl-logging";
+
+/// Map a level onto the OpenTelemetry severity number of the same name
+pub fn otel_severity(level: LogLevel) -> Severity {
+    match level {
+        LogLevel::Trace => Severity::Trace,
+        LogLevel::Debug => Severity::Debug,
+        LogLevel::Info => Severity::Info,
+        LogLevel::Warn => Severity::Warn,
+        LogLevel::Error => Severity::Error,
+        LogLevel::Fatal => Severity::Fatal,
+    }
+}
+
+fn any_value(value: &Value) -> Option<AnyValue> {
+    match value {
+        Value::Null => None,
+        Value::Bool(b) => Some(AnyValue::Boolean(*b)),
+        Value::Number(n) => Some(match n.as_i64() {
+            Some(i) => AnyValue::Int(i),
+            None => AnyValue::Double(n.as_f64().unwrap_or_default()),
+        }),
+        Value::String(s) => Some(AnyValue::String(s.clone().into())),
+        Value::Array(items) => Some(AnyValue::ListAny(Box::new(
+            items.iter().filter_map(any_value).collect(),
+        ))),
+        Value::Object(map) => Some(AnyValue::Map(Box::new(
+            map.iter()
+                .filter_map(|(k, v)| Some((Key::from(k.clone()), any_value(v)?)))
+                .collect(),
+        ))),
+    }
+}
+
+/// Adapter - Emits records as OpenTelemetry log records
+///
+/// Each record becomes one OTel log record with:
+///
+/// - the severity number and text for its level (see [`otel_severity`])
+/// - the message as body and the record timestamp
+/// - one attribute per field (`null` values are skipped), plus
+///   `request_id` and `tags` when present
+/// - the trace context, when the record carries hex `trace_id` and
+///   `span_id` fields; those two fields are then not repeated as attributes
+///
+/// Export happens through the provider's processors, so a batching
+/// processor keeps batching; [`Logger::flush`] forces it to export.
+pub struct OtlpLogger {
+    provider: SdkLoggerProvider,
+    logger: SdkLogger,
+}
+
+impl OtlpLogger {
+    /// Emit records through a logger from `provider`
+    pub fn new(provider: SdkLoggerProvider) -> Self {
+        let logger = provider.logger(OTEL_SCOPE);
+        Self { provider, logger }
+    }
+
+    /// Get the logger provider
+    pub fn provider(&self) -> &SdkLoggerProvider {
+        &self.provider
+    }
+
+    fn trace_context(record: &LogRecord) -> Option<(TraceId, SpanId)> {
+        let trace_id = record.fields().get("trace_id")?.as_str()?;
+        let span_id = record.fields().get("span_id")?.as_str()?;
+        Some((
+            TraceId::from_hex(trace_id).ok()?,
+            SpanId::from_hex(span_id).ok()?,
+        ))
+    }
+}
+
+impl Logger for OtlpLogger {
+    fn log(&self, record: &LogRecord) {
+        let mut otel = self.logger.create_log_record();
+        otel.set_timestamp(UNIX_EPOCH + Duration::from_secs(record.timestamp()));
+        otel.set_observed_timestamp(SystemTime::now());
+        otel.set_severity_number(otel_severity(record.level()));
+        otel.set_severity_text(format_level(record.level()));
+        otel.set_body(AnyValue::from(record.message().to_string()));
+
+        let trace_context = Self::trace_context(record);
+        if let Some((trace_id, span_id)) = trace_context {
+            otel.set_trace_context(trace_id, span_id, None);
+        }
+        for (key, value) in record.fields() {
+            if trace_context.is_some() && (key == "trace_id" || key == "span_id") {
+                continue;
+            }
+            if let Some(value) = any_value(value) {
+                otel.add_attribute(key.clone(), value);
+            }
+        }
+        if let Some(request_id) = record.request_id() {
+            otel.add_attribute("request_id", request_id.to_string());
+        }
+        if !record.tags().is_empty() {
+            let tags = record.tags().iter().cloned().map(AnyValue::from).collect();
+            otel.add_attribute("tags", AnyValue::ListAny(Box::new(tags)));
+        }
+        self.logger.emit(otel);
+    }
+
+    fn flush(&self) {
+        let _ = self.provider.force_flush();
+    }
+}
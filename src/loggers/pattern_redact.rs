@@ -0,0 +1,94 @@
+//! Pattern-based redacting logger
+//!
+//! Complements key-based redaction by masking secrets that leak into
+//! free-form text, such as a bearer token embedded in an error message.
+
+use super::redact::REDACTED_PLACEHOLDER;
+use super::Logger;
+use crate::record::LogRecord;
+use crate::LoggingResult;
+use regex::Regex;
+use serde_json::Value;
+use tyl_errors::TylError;
+
+/// Adapter - Replaces regex matches in messages and string fields with `"[REDACTED]"`
+///
+/// Patterns are compiled once at construction. String values nested inside
+/// arrays and objects are scanned as well.
+pub struct PatternRedactingLogger<L: Logger> {
+    inner: L,
+    patterns: Vec<Regex>,
+}
+
+impl<L: Logger> PatternRedactingLogger<L> {
+    /// Wrap `inner`, compiling `patterns`
+    ///
+    /// Returns a configuration error naming the first invalid pattern.
+    pub fn new<S: AsRef<str>>(
+        inner: L,
+        patterns: impl IntoIterator<Item = S>,
+    ) -> LoggingResult<Self> {
+        let patterns = patterns
+            .into_iter()
+            .map(|pattern| {
+                Regex::new(pattern.as_ref()).map_err(|err| {
+                    TylError::configuration(format!(
+                        "invalid redaction pattern {}: {}",
+                        pattern.as_ref(),
+                        err
+                    ))
+                })
+            })
+            .collect::<LoggingResult<Vec<_>>>()?;
+        Ok(Self { inner, patterns })
+    }
+
+    /// Get the wrapped logger
+    pub fn inner(&self) -> &L {
+        &self.inner
+    }
+
+    fn redact_str(&self, text: &str) -> String {
+        self.patterns
+            .iter()
+            .fold(text.to_string(), |text, pattern| {
+                pattern
+                    .replace_all(&text, REDACTED_PLACEHOLDER)
+                    .into_owned()
+            })
+    }
+
+    fn redact_value(&self, value: &Value) -> Value {
+        match value {
+            Value::String(s) => Value::String(self.redact_str(s)),
+            Value::Array(items) => {
+                Value::Array(items.iter().map(|v| self.redact_value(v)).collect())
+            }
+            Value::Object(map) => Value::Object(
+                map.iter()
+                    .map(|(k, v)| (k.clone(), self.redact_value(v)))
+                    .collect(),
+            ),
+            other => other.clone(),
+        }
+    }
+}
+
+impl<L: Logger> Logger for PatternRedactingLogger<L> {
+    fn log(&self, record: &LogRecord) {
+        let mut redacted = record.clone();
+        redacted.set_message(self.redact_str(record.message()));
+        for value in redacted.fields_mut().values_mut() {
+            *value = self.redact_value(value);
+        }
+        self.inner.log(&redacted);
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+
+    fn barrier(&self) {
+        self.inner.barrier();
+    }
+}
@@ -0,0 +1,136 @@
+//! Protobuf logger implementation
+//!
+//! Encodes records as protobuf messages for gRPC log streaming. The
+//! message layout, as a `.proto` definition:
+//!
+//! ```text
+//! enum Level { TRACE = 0; DEBUG = 1; INFO = 2; WARN = 3; ERROR = 4; FATAL = 5; }
+//!
+//! message LogEntry {
+//!   Level level = 1;
+//!   string message = 2;
+//!   uint64 timestamp = 3;
+//!   map<string, string> fields = 4;
+//!   optional string request_id = 5;
+//!   repeated string tags = 6;
+//! }
+//! ```
+
+use super::Logger;
+use crate::record::{LogLevel, LogRecord};
+use prost::Message;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::io::Write;
+use std::sync::Mutex;
+
+/// Protobuf mirror of [`LogLevel`], with the same numeric values
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+#[repr(i32)]
+pub enum ProtoLevel {
+    Trace = 0,
+    Debug = 1,
+    Info = 2,
+    Warn = 3,
+    Error = 4,
+    Fatal = 5,
+}
+
+impl From<LogLevel> for ProtoLevel {
+    fn from(level: LogLevel) -> Self {
+        match level {
+            LogLevel::Trace => ProtoLevel::Trace,
+            LogLevel::Debug => ProtoLevel::Debug,
+            LogLevel::Info => ProtoLevel::Info,
+            LogLevel::Warn => ProtoLevel::Warn,
+            LogLevel::Error => ProtoLevel::Error,
+            LogLevel::Fatal => ProtoLevel::Fatal,
+        }
+    }
+}
+
+/// Protobuf encoding of a [`LogRecord`]
+///
+/// Field values are stringified: JSON strings are stored as-is and every
+/// other value (numbers, booleans, `null`, arrays, objects) as its compact
+/// JSON text, e.g. `42`, `true`, or `{"a":1}`.
+#[derive(Clone, PartialEq, Message)]
+pub struct LogEntry {
+    #[prost(enumeration = "ProtoLevel", tag = "1")]
+    pub level: i32,
+    #[prost(string, tag = "2")]
+    pub message: String,
+    #[prost(uint64, tag = "3")]
+    pub timestamp: u64,
+    #[prost(map = "string, string", tag = "4")]
+    pub fields: HashMap<String, String>,
+    #[prost(string, optional, tag = "5")]
+    pub request_id: Option<String>,
+    #[prost(string, repeated, tag = "6")]
+    pub tags: Vec<String>,
+}
+
+impl From<&LogRecord> for LogEntry {
+    fn from(record: &LogRecord) -> Self {
+        Self {
+            level: ProtoLevel::from(record.level()) as i32,
+            message: record.message().to_string(),
+            timestamp: record.timestamp(),
+            fields: record
+                .fields()
+                .iter()
+                .map(|(key, value)| {
+                    let value = match value {
+                        Value::String(s) => s.clone(),
+                        other => other.to_string(),
+                    };
+                    (key.clone(), value)
+                })
+                .collect(),
+            request_id: record.request_id().map(str::to_string),
+            tags: record.tags().to_vec(),
+        }
+    }
+}
+
+/// Adapter - Writes each record as a length-delimited [`LogEntry`] frame
+///
+/// Each frame is a varint byte length followed by the encoded message, the
+/// framing read by `LogEntry::decode_length_delimited`.
+pub struct ProtobufLogger {
+    writer: Mutex<Box<dyn Write + Send>>,
+}
+
+impl ProtobufLogger {
+    /// Create a protobuf logger writing frames to `writer`
+    pub fn with_writer(writer: impl Write + Send + 'static) -> Self {
+        Self {
+            writer: Mutex::new(Box::new(writer)),
+        }
+    }
+}
+
+impl Logger for ProtobufLogger {
+    fn log(&self, record: &LogRecord) {
+        let frame = LogEntry::from(record).encode_length_delimited_to_vec();
+        let mut writer = self.writer.lock().unwrap_or_else(|e| e.into_inner());
+        let _ = writer.write_all(&frame);
+    }
+
+    fn log_batch(&self, records: &[LogRecord]) {
+        let mut frames = Vec::new();
+        for record in records {
+            frames.extend(LogEntry::from(record).encode_length_delimited_to_vec());
+        }
+        let mut writer = self.writer.lock().unwrap_or_else(|e| e.into_inner());
+        let _ = writer.write_all(&frames);
+    }
+
+    fn flush(&self) {
+        let _ = self
+            .writer
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .flush();
+    }
+}
@@ -0,0 +1,88 @@
+//! Quiet-until-error logger
+//!
+//! Keeps CLI output clean on success while still providing the lead-up to a
+//! failure when something goes wrong.
+
+use super::Logger;
+use crate::record::{LogLevel, LogRecord};
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+struct QuietState {
+    buffer: VecDeque<LogRecord>,
+    triggered: bool,
+}
+
+/// Adapter - Holds records back until the first `Error`, then replays them
+///
+/// Up to `capacity` of the most recent records are buffered (older ones are
+/// discarded first). The first record at `Error` or above flushes the buffer
+/// and that record to the inner logger, after which every record passes
+/// straight through. If no error ever occurs, the buffer is discarded when
+/// the logger is dropped.
+pub struct QuietUntilErrorLogger<L: Logger> {
+    inner: L,
+    capacity: usize,
+    state: Mutex<QuietState>,
+}
+
+impl<L: Logger> QuietUntilErrorLogger<L> {
+    /// Wrap `inner`, buffering at most `capacity` records before an error
+    pub fn new(inner: L, capacity: usize) -> Self {
+        Self {
+            inner,
+            capacity,
+            state: Mutex::new(QuietState {
+                buffer: VecDeque::new(),
+                triggered: false,
+            }),
+        }
+    }
+
+    /// Get the wrapped logger
+    pub fn inner(&self) -> &L {
+        &self.inner
+    }
+
+    /// Whether an error has switched the logger to pass-through
+    pub fn is_triggered(&self) -> bool {
+        self.state
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .triggered
+    }
+}
+
+impl<L: Logger> Logger for QuietUntilErrorLogger<L> {
+    fn log(&self, record: &LogRecord) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        if state.triggered {
+            drop(state);
+            self.inner.log(record);
+            return;
+        }
+        if record.level() >= LogLevel::Error {
+            state.triggered = true;
+            let mut buffered: Vec<LogRecord> = state.buffer.drain(..).collect();
+            drop(state);
+            buffered.push(record.clone());
+            self.inner.log_batch(&buffered);
+            return;
+        }
+        if self.capacity == 0 {
+            return;
+        }
+        if state.buffer.len() == self.capacity {
+            state.buffer.pop_front();
+        }
+        state.buffer.push_back(record.clone());
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+
+    fn barrier(&self) {
+        self.inner.barrier();
+    }
+}
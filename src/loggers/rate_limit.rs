@@ -0,0 +1,218 @@
+//! Rate-limiting logger
+//!
+//! Caps how many records per bucket are forwarded within a time window, so a
+//! hot error path can't flood the output.
+
+use super::Logger;
+use crate::record::{LogLevel, LogRecord};
+use crate::utils::format_level;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+type KeyFn = Box<dyn Fn(&LogRecord) -> String + Send + Sync>;
+type BucketKey = (Option<LogLevel>, Option<String>);
+
+struct Bucket {
+    window_start: Instant,
+    forwarded: u32,
+    suppressed: u64,
+}
+
+struct Buckets {
+    by_key: HashMap<BucketKey, Bucket>,
+    last_sweep: Instant,
+}
+
+/// Adapter - Forwards at most `max_per_window` records per bucket per window
+///
+/// Records are grouped into buckets by a key function (the message by
+/// default), and each bucket has its own budget. When a bucket's window
+/// rolls over after suppressing records, a `Warn` summary with
+/// `rate_limit_key` and `suppressed` fields is emitted before the next record.
+/// At most once per window, and on [`flush`](Logger::flush), buckets whose
+/// window has expired are evicted, emitting their summary if they suppressed
+/// anything, so keys that never repeat neither accumulate nor lose counts.
+///
+/// With [`with_level_limits`](Self::with_level_limits) each level has its
+/// own budget instead, shared by every record of that level unless a key
+/// function is set, and summaries carry a `rate_limit_level` field.
+pub struct RateLimitingLogger<L: Logger> {
+    inner: L,
+    max_per_window: u32,
+    window: Duration,
+    key_fn: Option<KeyFn>,
+    level_limits: Option<[Option<u32>; 6]>,
+    buckets: Mutex<Buckets>,
+}
+
+impl<L: Logger> RateLimitingLogger<L> {
+    /// Wrap `inner`, allowing `max_per_window` records per message per `window`
+    pub fn new(inner: L, max_per_window: u32, window: Duration) -> Self {
+        Self {
+            inner,
+            max_per_window,
+            window,
+            key_fn: None,
+            level_limits: None,
+            buckets: Mutex::new(Buckets {
+                by_key: HashMap::new(),
+                last_sweep: Instant::now(),
+            }),
+        }
+    }
+
+    /// Derive the throttle bucket from the record, e.g. from chosen fields
+    ///
+    /// With [`with_level_limits`](Self::with_level_limits), buckets are then
+    /// kept per level and key rather than per level alone.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use tyl_logging::{JsonLogger, RateLimitingLogger};
+    ///
+    /// let logger = RateLimitingLogger::new(JsonLogger::new(), 10, Duration::from_secs(1))
+    ///     .with_key_fn(|record| {
+    ///         format!("{}:{}", record.fields()["endpoint"], record.fields()["status"])
+    ///     });
+    /// ```
+    pub fn with_key_fn(
+        mut self,
+        key_fn: impl Fn(&LogRecord) -> String + Send + Sync + 'static,
+    ) -> Self {
+        self.key_fn = Some(Box::new(key_fn));
+        self
+    }
+
+    /// Give each level its own `max_per_window`; levels not listed are unlimited
+    ///
+    /// Replaces the single limit passed to [`new`](Self::new). All records of
+    /// a limited level share its budget, whatever their message; set
+    /// [`with_key_fn`](Self::with_key_fn) to budget each key separately.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use tyl_logging::{JsonLogger, LogLevel, RateLimitingLogger};
+    ///
+    /// // At most 5 debug records per second, errors never limited
+    /// let logger = RateLimitingLogger::new(JsonLogger::new(), 10, Duration::from_secs(1))
+    ///     .with_level_limits([(LogLevel::Debug, 5)]);
+    /// ```
+    pub fn with_level_limits(mut self, limits: impl IntoIterator<Item = (LogLevel, u32)>) -> Self {
+        let mut level_limits = [None; 6];
+        for (level, limit) in limits {
+            level_limits[level as usize] = Some(limit);
+        }
+        self.level_limits = Some(level_limits);
+        self
+    }
+
+    /// Get the wrapped logger
+    pub fn inner(&self) -> &L {
+        &self.inner
+    }
+
+    /// Evict expired buckets, returning summaries for those that suppressed records
+    fn sweep(&self, buckets: &mut Buckets, now: Instant) -> Vec<LogRecord> {
+        buckets.last_sweep = now;
+        let mut summaries = Vec::new();
+        buckets.by_key.retain(|(level, key), bucket| {
+            if now.duration_since(bucket.window_start) < self.window {
+                return true;
+            }
+            if bucket.suppressed > 0 {
+                summaries.push(suppressed_summary(
+                    *level,
+                    key.as_deref(),
+                    bucket.suppressed,
+                ));
+            }
+            false
+        });
+        summaries
+    }
+}
+
+fn suppressed_summary(level: Option<LogLevel>, key: Option<&str>, suppressed: u64) -> LogRecord {
+    let mut notice = LogRecord::new(LogLevel::Warn, "rate limit suppressed records");
+    if let Some(key) = key {
+        notice.add_field("rate_limit_key", serde_json::json!(key));
+    }
+    notice.add_field("suppressed", serde_json::json!(suppressed));
+    if let Some(level) = level {
+        notice.add_field("rate_limit_level", serde_json::json!(format_level(level)));
+    }
+    notice
+}
+
+impl<L: Logger> Logger for RateLimitingLogger<L> {
+    fn log(&self, record: &LogRecord) {
+        let (level, max_per_window) = match &self.level_limits {
+            None => (None, self.max_per_window),
+            Some(limits) => match limits[record.level() as usize] {
+                Some(limit) => (Some(record.level()), limit),
+                None => {
+                    self.inner.log(record);
+                    return;
+                }
+            },
+        };
+        let key = match (&self.key_fn, level) {
+            (Some(key_fn), _) => Some(key_fn(record)),
+            (None, Some(_)) => None,
+            (None, None) => Some(record.message().to_string()),
+        };
+        let now = Instant::now();
+        let mut summaries = Vec::new();
+        let allowed = {
+            let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+            if now.duration_since(buckets.last_sweep) >= self.window {
+                summaries = self.sweep(&mut buckets, now);
+            }
+            let bucket = buckets
+                .by_key
+                .entry((level, key.clone()))
+                .or_insert(Bucket {
+                    window_start: now,
+                    forwarded: 0,
+                    suppressed: 0,
+                });
+            if now.duration_since(bucket.window_start) >= self.window {
+                if bucket.suppressed > 0 {
+                    summaries.push(suppressed_summary(level, key.as_deref(), bucket.suppressed));
+                }
+                bucket.window_start = now;
+                bucket.forwarded = 0;
+                bucket.suppressed = 0;
+            }
+            if bucket.forwarded < max_per_window {
+                bucket.forwarded += 1;
+                true
+            } else {
+                bucket.suppressed += 1;
+                false
+            }
+        };
+        for summary in &summaries {
+            self.inner.log(summary);
+        }
+        if allowed {
+            self.inner.log(record);
+        }
+    }
+
+    fn flush(&self) {
+        let summaries = {
+            let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+            self.sweep(&mut buckets, Instant::now())
+        };
+        for summary in &summaries {
+            self.inner.log(summary);
+        }
+        self.inner.flush();
+    }
+
+    fn barrier(&self) {
+        self.inner.barrier();
+    }
+}
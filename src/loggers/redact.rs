@@ -0,0 +1,56 @@
+//! Redacting logger
+//!
+//! Masks the values of sensitive fields such as passwords and tokens before
+//! they are written anywhere.
+
+use super::Logger;
+use crate::record::LogRecord;
+use std::collections::HashSet;
+
+/// Replacement value for redacted data
+pub const REDACTED_PLACEHOLDER: &str = "[REDACTED]";
+
+/// Adapter - Replaces the values of configured field keys with `"[REDACTED]"`
+pub struct RedactingLogger<L: Logger> {
+    inner: L,
+    keys: HashSet<String>,
+}
+
+impl<L: Logger> RedactingLogger<L> {
+    /// Wrap `inner`, redacting fields whose key is in `keys`
+    pub fn new<K: Into<String>>(inner: L, keys: impl IntoIterator<Item = K>) -> Self {
+        Self {
+            inner,
+            keys: keys.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Get the wrapped logger
+    pub fn inner(&self) -> &L {
+        &self.inner
+    }
+}
+
+impl<L: Logger> Logger for RedactingLogger<L> {
+    fn log(&self, record: &LogRecord) {
+        if !record.fields().keys().any(|key| self.keys.contains(key)) {
+            self.inner.log(record);
+            return;
+        }
+        let mut record = record.clone();
+        for key in &self.keys {
+            if let Some(value) = record.fields_mut().get_mut(key) {
+                *value = serde_json::json!(REDACTED_PLACEHOLDER);
+            }
+        }
+        self.inner.log(&record);
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+
+    fn barrier(&self) {
+        self.inner.barrier();
+    }
+}
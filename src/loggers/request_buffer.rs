@@ -0,0 +1,126 @@
+//! Per-request buffering logger
+//!
+//! Collects every record logged while a request's [`LogContext`](crate::LogContext)
+//! is active and emits them as one aggregated record when the request ends,
+//! so a request's output is atomic and trivially correlated.
+
+use super::Logger;
+use crate::context::capture_context;
+use crate::record::{LogLevel, LogRecord};
+use crate::utils::format_level;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Message of the aggregated record emitted by [`RequestBufferLogger::flush_request`]
+pub const REQUEST_SUMMARY_MESSAGE: &str = "request completed";
+
+/// Adapter - Buffers records per context request ID
+///
+/// Records logged with a request ID in the current [`LogContext`](crate::LogContext)
+/// are held until [`flush_request`](Self::flush_request) is called for that
+/// ID. Records logged without one are passed straight through.
+///
+/// [`flush`](Logger::flush) and dropping the logger emit every request still
+/// buffered, in request ID order, so nothing is lost at shutdown.
+pub struct RequestBufferLogger<L: Logger> {
+    inner: L,
+    buffers: Mutex<HashMap<String, Vec<LogRecord>>>,
+}
+
+impl<L: Logger> RequestBufferLogger<L> {
+    /// Wrap `inner`, buffering records by request ID
+    pub fn new(inner: L) -> Self {
+        Self {
+            inner,
+            buffers: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Get the wrapped logger
+    pub fn inner(&self) -> &L {
+        &self.inner
+    }
+
+    /// Emit the records buffered for `request_id` as one combined record
+    ///
+    /// The combined record takes the most severe buffered level, carries the
+    /// request ID, and holds the individual records in a `logs` array field.
+    /// Returns `false` if nothing was buffered for `request_id`.
+    pub fn flush_request(&self, request_id: &str) -> bool {
+        let records = self
+            .buffers
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(request_id);
+        let Some(records) = records else {
+            return false;
+        };
+        self.emit(request_id, records);
+        true
+    }
+
+    fn emit(&self, request_id: &str, records: Vec<LogRecord>) {
+        let level = records
+            .iter()
+            .map(LogRecord::level)
+            .max()
+            .unwrap_or(LogLevel::Info);
+        let logs: Vec<Value> = records
+            .iter()
+            .map(|record| {
+                json!({
+                    "timestamp": record.timestamp(),
+                    "level": format_level(record.level()),
+                    "message": record.message(),
+                    "fields": record.fields(),
+                })
+            })
+            .collect();
+
+        let mut combined =
+            LogRecord::new(level, REQUEST_SUMMARY_MESSAGE).with_request_id(request_id.to_string());
+        combined.add_field("logs", Value::Array(logs));
+        self.inner.log(&combined);
+    }
+}
+
+impl<L: Logger> Logger for RequestBufferLogger<L> {
+    fn log(&self, record: &LogRecord) {
+        let context = capture_context();
+        match context.request_id() {
+            Some(request_id) => self
+                .buffers
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .entry(request_id.to_string())
+                .or_default()
+                .push(record.clone()),
+            None => self.inner.log(record),
+        }
+    }
+
+    fn flush(&self) {
+        let mut pending: Vec<_> = self
+            .buffers
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .drain()
+            .collect();
+        pending.sort_by(|a, b| a.0.cmp(&b.0));
+        for (request_id, records) in pending {
+            self.emit(&request_id, records);
+        }
+        self.inner.flush();
+    }
+
+    fn barrier(&self) {
+        self.inner.barrier();
+    }
+}
+
+impl<L: Logger> Drop for RequestBufferLogger<L> {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
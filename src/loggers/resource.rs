@@ -0,0 +1,68 @@
+//! OpenTelemetry resource logger
+//!
+//! Attaches the OpenTelemetry resource attributes an OTel collector expects
+//! to find on every record.
+
+use super::Logger;
+use crate::config::LoggingConfig;
+use crate::record::LogRecord;
+use serde_json::{json, Value};
+
+/// Adapter - Adds a nested `resource` object to every record
+///
+/// The object carries `service.name` and `deployment.environment` from the
+/// [`LoggingConfig`] and the given `service.version`. It replaces any
+/// `resource` field already on the record.
+pub struct ResourceLogger<L: Logger> {
+    inner: L,
+    resource: Value,
+}
+
+impl<L: Logger> ResourceLogger<L> {
+    /// Wrap `inner`, describing the service from `config` and `service_version`
+    ///
+    /// ```rust
+    /// use tyl_logging::{JsonLogger, LoggingConfig, ResourceLogger};
+    ///
+    /// let config = LoggingConfig::new("checkout");
+    /// let logger = ResourceLogger::new(JsonLogger::new(), &config, env!("CARGO_PKG_VERSION"));
+    /// ```
+    pub fn new(inner: L, config: &LoggingConfig, service_version: impl Into<String>) -> Self {
+        Self {
+            inner,
+            resource: json!({
+                "service.name": config.service_name(),
+                "service.version": service_version.into(),
+                "deployment.environment": config.environment().as_str(),
+            }),
+        }
+    }
+
+    /// Get the resource object added to each record
+    pub fn resource(&self) -> &Value {
+        &self.resource
+    }
+
+    /// Get the wrapped logger
+    pub fn inner(&self) -> &L {
+        &self.inner
+    }
+}
+
+impl<L: Logger> Logger for ResourceLogger<L> {
+    fn log(&self, record: &LogRecord) {
+        let mut record = record.clone();
+        record
+            .fields_mut()
+            .insert("resource".to_string(), self.resource.clone());
+        self.inner.log(&record);
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+
+    fn barrier(&self) {
+        self.inner.barrier();
+    }
+}
@@ -0,0 +1,298 @@
+//! Object store archive logger
+//!
+//! Archives records to S3, MinIO, or any other store supported by the
+//! `object_store` crate, as gzip-compressed NDJSON objects.
+
+use super::Logger;
+use crate::diagnostics::{Diagnostics, ISSUE_DROPPED_RECORDS};
+use crate::format::{Formatter, JsonFormatter};
+use crate::record::LogRecord;
+use crate::utils::format_rfc3339;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use object_store::path::Path;
+use object_store::{ObjectStore, PutPayload};
+use std::collections::VecDeque;
+use std::io::Write;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Uncompressed object size used by [`ObjectStoreLogger::new`]
+pub const DEFAULT_OBJECT_MAX_BYTES: usize = 8 * 1024 * 1024;
+
+/// Flush interval used by [`ObjectStoreLogger::new`]
+pub const DEFAULT_OBJECT_FLUSH_INTERVAL: Duration = Duration::from_secs(300);
+
+/// Retry count used by [`ObjectStoreLogger::new`]
+pub const DEFAULT_OBJECT_MAX_RETRIES: u32 = 3;
+
+/// Compressed bytes held for a later retry by [`ObjectStoreLogger::new`]
+pub const DEFAULT_OBJECT_FALLBACK_BYTES: usize = 64 * 1024 * 1024;
+
+struct Pending {
+    lines: Vec<u8>,
+    records: u64,
+    last_flush: Instant,
+}
+
+struct Upload {
+    key: Path,
+    body: Vec<u8>,
+    records: u64,
+}
+
+/// Adapter - Uploads buffered records as gzip-compressed NDJSON objects
+///
+/// Objects are keyed `{service}/{YYYY-MM-DD}/{uuid}.ndjson.gz`. An object is
+/// written once the buffered NDJSON reaches the maximum object size, when a
+/// record arrives after the flush interval has elapsed, on
+/// [`Logger::flush`], and on drop. Like [`HttpLogger`](super::HttpLogger)
+/// there is no background timer.
+///
+/// Uploads run on the logging thread, each on a short-lived helper thread
+/// with its own Tokio runtime, so logging from inside an async runtime is
+/// fine. A failed upload is retried with linear backoff; if every attempt
+/// fails the object is kept in memory and retried with the next upload.
+/// The kept objects are bounded by the fallback size; the oldest are
+/// dropped beyond it, counted in [`dropped`](Self::dropped), and reported as
+/// a throttled diagnostic.
+///
+/// ```rust,ignore
+/// use object_store::aws::AmazonS3Builder;
+/// use std::sync::Arc;
+/// use tyl_logging::ObjectStoreLogger;
+///
+/// let store = AmazonS3Builder::from_env().with_bucket_name("logs").build()?;
+/// let logger = ObjectStoreLogger::new(Arc::new(store), "billing");
+/// ```
+pub struct ObjectStoreLogger {
+    store: Arc<dyn ObjectStore>,
+    service: String,
+    max_object_bytes: usize,
+    flush_interval: Duration,
+    max_retries: u32,
+    retry_backoff: Duration,
+    max_fallback_bytes: usize,
+    formatter: JsonFormatter,
+    pending: Mutex<Pending>,
+    fallback: Mutex<VecDeque<Upload>>,
+    dropped: AtomicU64,
+    diagnostics: Diagnostics,
+}
+
+impl ObjectStoreLogger {
+    /// Create a logger writing objects for `service` to `store`
+    pub fn new(store: Arc<dyn ObjectStore>, service: impl Into<String>) -> Self {
+        Self {
+            store,
+            service: service.into(),
+            max_object_bytes: DEFAULT_OBJECT_MAX_BYTES,
+            flush_interval: DEFAULT_OBJECT_FLUSH_INTERVAL,
+            max_retries: DEFAULT_OBJECT_MAX_RETRIES,
+            retry_backoff: Duration::from_millis(500),
+            max_fallback_bytes: DEFAULT_OBJECT_FALLBACK_BYTES,
+            formatter: JsonFormatter::new(),
+            pending: Mutex::new(Pending {
+                lines: Vec::new(),
+                records: 0,
+                last_flush: Instant::now(),
+            }),
+            fallback: Mutex::new(VecDeque::new()),
+            dropped: AtomicU64::new(0),
+            diagnostics: Diagnostics::new(),
+        }
+    }
+
+    /// Write an object once `bytes` of uncompressed NDJSON are buffered (minimum 1)
+    pub fn with_max_object_bytes(mut self, bytes: usize) -> Self {
+        self.max_object_bytes = bytes.max(1);
+        self
+    }
+
+    /// Write the buffered records when a record arrives `interval` after the last write
+    pub fn with_flush_interval(mut self, interval: Duration) -> Self {
+        self.flush_interval = interval;
+        self
+    }
+
+    /// Retry a failed upload up to `retries` times before keeping it for later
+    pub fn with_max_retries(mut self, retries: u32) -> Self {
+        self.max_retries = retries;
+        self
+    }
+
+    /// Wait `backoff` times the attempt number between retries
+    pub fn with_retry_backoff(mut self, backoff: Duration) -> Self {
+        self.retry_backoff = backoff;
+        self
+    }
+
+    /// Keep at most `bytes` of compressed objects whose upload failed
+    pub fn with_max_fallback_bytes(mut self, bytes: usize) -> Self {
+        self.max_fallback_bytes = bytes;
+        self
+    }
+
+    /// Report dropped objects through `diagnostics` instead of stderr
+    pub fn with_diagnostics(mut self, diagnostics: Diagnostics) -> Self {
+        self.diagnostics = diagnostics;
+        self
+    }
+
+    /// Get the service name used as the key prefix
+    pub fn service(&self) -> &str {
+        &self.service
+    }
+
+    /// Number of records dropped because the fallback buffer was full
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    fn take_batch(&self, force: bool) -> Option<(Vec<u8>, u64)> {
+        let mut pending = self.pending.lock().unwrap_or_else(|e| e.into_inner());
+        let due = force
+            || pending.lines.len() >= self.max_object_bytes
+            || pending.last_flush.elapsed() >= self.flush_interval;
+        if !due || pending.lines.is_empty() {
+            return None;
+        }
+        pending.last_flush = Instant::now();
+        let records = std::mem::take(&mut pending.records);
+        Some((std::mem::take(&mut pending.lines), records))
+    }
+
+    fn object_key(&self) -> Path {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let date = &format_rfc3339(now)[..10];
+        Path::from(format!(
+            "{}/{}/{}.ndjson.gz",
+            self.service,
+            date,
+            uuid::Uuid::new_v4()
+        ))
+    }
+
+    fn put(&self, upload: &Upload) -> bool {
+        let payload = PutPayload::from(upload.body.clone());
+        std::thread::scope(|scope| {
+            scope
+                .spawn(|| {
+                    let runtime = tokio::runtime::Builder::new_current_thread()
+                        .enable_all()
+                        .build()
+                        .ok()?;
+                    runtime.block_on(self.store.put(&upload.key, payload)).ok()
+                })
+                .join()
+                .ok()
+                .flatten()
+                .is_some()
+        })
+    }
+
+    fn put_with_retries(&self, upload: &Upload) -> bool {
+        for attempt in 0..=self.max_retries {
+            if attempt > 0 {
+                std::thread::sleep(self.retry_backoff * attempt);
+            }
+            if self.put(upload) {
+                return true;
+            }
+        }
+        false
+    }
+
+    fn upload(&self, force: bool) {
+        let batch = self.take_batch(force);
+        if batch.is_none() && !force {
+            return;
+        }
+        let mut uploads: VecDeque<Upload> =
+            std::mem::take(&mut *self.fallback.lock().unwrap_or_else(|e| e.into_inner()));
+        if let Some((lines, records)) = batch {
+            match compress(&lines) {
+                Ok(body) => uploads.push_back(Upload {
+                    key: self.object_key(),
+                    body,
+                    records,
+                }),
+                Err(err) => self.drop_records(records, format_args!("compression failed: {err}")),
+            }
+        }
+        while let Some(upload) = uploads.front() {
+            if !self.put_with_retries(upload) {
+                break;
+            }
+            uploads.pop_front();
+        }
+        if uploads.is_empty() {
+            return;
+        }
+
+        // Requeue what failed behind anything queued meanwhile, within the bound
+        let mut fallback = self.fallback.lock().unwrap_or_else(|e| e.into_inner());
+        uploads.extend(fallback.drain(..));
+        let mut held: usize = uploads.iter().map(|upload| upload.body.len()).sum();
+        let mut dropped = 0;
+        while held > self.max_fallback_bytes {
+            let Some(oldest) = uploads.pop_front() else {
+                break;
+            };
+            held -= oldest.body.len();
+            dropped += oldest.records;
+        }
+        *fallback = uploads;
+        drop(fallback);
+        if dropped > 0 {
+            self.drop_records(
+                dropped,
+                format_args!(
+                    "fallback buffer full after failed uploads for {}",
+                    self.service
+                ),
+            );
+        }
+    }
+
+    fn drop_records(&self, count: u64, reason: std::fmt::Arguments<'_>) {
+        self.dropped.fetch_add(count, Ordering::Relaxed);
+        self.diagnostics.report(
+            ISSUE_DROPPED_RECORDS,
+            format_args!("{count} records dropped: {reason}"),
+        );
+    }
+}
+
+fn compress(bytes: &[u8]) -> std::io::Result<Vec<u8>> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(bytes)?;
+    encoder.finish()
+}
+
+impl Logger for ObjectStoreLogger {
+    fn log(&self, record: &LogRecord) {
+        let line = self.formatter.format(record);
+        {
+            let mut pending = self.pending.lock().unwrap_or_else(|e| e.into_inner());
+            pending.lines.extend_from_slice(&line);
+            pending.lines.push(b'\n');
+            pending.records += 1;
+        }
+        self.upload(false);
+    }
+
+    fn flush(&self) {
+        self.upload(true);
+    }
+}
+
+impl Drop for ObjectStoreLogger {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
@@ -0,0 +1,120 @@
+//! Sampling logger
+//!
+//! Forwards a fixed fraction of records to cut volume from noisy sources,
+//! optionally letting important records bypass sampling.
+
+use super::Logger;
+use crate::record::{LogLevel, LogRecord};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// How a [`SamplingLogger`] treats a record
+///
+/// `Always` and `NeverDrop` are equivalent: both forward the record without
+/// sampling it. `NeverDrop` lets a priority function state that a record
+/// must survive, as the logger guarantees for `Error` and above.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SamplePriority {
+    /// Always forward the record
+    Always,
+    /// Forward the record if sampling keeps it
+    Sampled,
+    /// Always forward the record, equivalent to `Always`
+    NeverDrop,
+}
+
+type PriorityFn = Box<dyn Fn(&LogRecord) -> SamplePriority + Send + Sync>;
+
+/// Adapter - Forwards approximately `rate` of all records
+///
+/// Sampling is deterministic rather than random: record `n` is kept when
+/// `floor((n + 1) * rate)` exceeds `floor(n * rate)`, so a rate of `0.25`
+/// keeps exactly every fourth record. Records at `Error` or above are never
+/// sampled and always forwarded. With a priority function, only the
+/// records below `Error` that it marks [`SamplePriority::Sampled`] are
+/// sampled and counted; the rest are always forwarded.
+pub struct SamplingLogger<L: Logger> {
+    inner: L,
+    rate: f64,
+    seen: AtomicU64,
+    priority: Option<PriorityFn>,
+}
+
+impl<L: Logger> SamplingLogger<L> {
+    /// Wrap `inner`, keeping `rate` (clamped to `0.0..=1.0`) of records
+    pub fn new(inner: L, rate: f64) -> Self {
+        Self {
+            inner,
+            rate: rate.clamp(0.0, 1.0),
+            seen: AtomicU64::new(0),
+            priority: None,
+        }
+    }
+
+    /// Decide per record below `Error` whether it is sampled or always forwarded
+    ///
+    /// ```rust
+    /// use tyl_logging::{JsonLogger, SamplePriority, SamplingLogger};
+    ///
+    /// let logger = SamplingLogger::new(JsonLogger::new(), 0.1).with_priority(|record| {
+    ///     if record.fields().contains_key("audit") {
+    ///         SamplePriority::NeverDrop
+    ///     } else if record.fields().get("priority").is_some_and(|p| p == "high") {
+    ///         SamplePriority::Always
+    ///     } else {
+    ///         SamplePriority::Sampled
+    ///     }
+    /// });
+    /// ```
+    pub fn with_priority(
+        mut self,
+        priority: impl Fn(&LogRecord) -> SamplePriority + Send + Sync + 'static,
+    ) -> Self {
+        self.priority = Some(Box::new(priority));
+        self
+    }
+
+    /// Get the sample rate
+    pub fn rate(&self) -> f64 {
+        self.rate
+    }
+
+    /// Get the wrapped logger
+    pub fn inner(&self) -> &L {
+        &self.inner
+    }
+}
+
+impl<L: Logger> Logger for SamplingLogger<L> {
+    fn log(&self, record: &LogRecord) {
+        if record.level() >= LogLevel::Error {
+            self.inner.log(record);
+            return;
+        }
+        let priority = self
+            .priority
+            .as_ref()
+            .map_or(SamplePriority::Sampled, |priority| priority(record));
+        if priority != SamplePriority::Sampled {
+            self.inner.log(record);
+            return;
+        }
+        let n = self.seen.fetch_add(1, Ordering::Relaxed);
+        if keep_nth(n, self.rate) {
+            self.inner.log(record);
+        }
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+
+    fn barrier(&self) {
+        self.inner.barrier();
+    }
+}
+
+/// Whether the `n`th record (zero-based) is kept at `rate`
+pub(crate) fn keep_nth(n: u64, rate: f64) -> bool {
+    let n = n as f64;
+    ((n + 1.0) * rate).floor() > (n * rate).floor()
+}
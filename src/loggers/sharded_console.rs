@@ -0,0 +1,178 @@
+//! Sharded console logger implementation
+//!
+//! A console logger for very hot, highly concurrent services, trading
+//! strict cross-thread line ordering for less lock contention on stdout.
+
+use super::Logger;
+use crate::diagnostics::{Diagnostics, ISSUE_SINK_WRITE_FAILED};
+use crate::format::{ConsoleFormatter, Formatter};
+use crate::record::LogRecord;
+use std::cell::RefCell;
+use std::io::Write;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Buffered bytes that trigger a thread's write to stdout by default
+pub const DEFAULT_THREAD_FLUSH_BYTES: usize = 8 * 1024;
+
+type ThreadBuffer = Arc<Mutex<Vec<u8>>>;
+
+static NEXT_LOGGER_ID: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    // This thread's buffer for each sharded logger it has logged to, by logger ID
+    static THREAD_BUFFERS: RefCell<Vec<(usize, ThreadBuffer)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Adapter - Console logger buffering per thread, written in batches
+///
+/// Each thread appends its formatted lines to its own thread-local buffer,
+/// so threads only meet on the writer, and a buffer is written out in one
+/// `write_all` once it holds [`DEFAULT_THREAD_FLUSH_BYTES`] (see
+/// [`with_flush_threshold`](Self::with_flush_threshold)). Output takes the
+/// stdout lock once per batch instead of once per record. Write failures
+/// are reported as throttled diagnostics.
+///
+/// # Ordering
+///
+/// Lines from one thread keep their order. Lines from different threads
+/// can appear out of order by up to one batch, and a quiet thread's lines
+/// stay buffered until [`Logger::flush`] is called or the logger is
+/// dropped, both of which write out every thread's buffer, including those
+/// of threads that have exited. Use [`ConsoleLogger`](super::ConsoleLogger)
+/// where output must be strictly ordered or immediate.
+pub struct ShardedConsoleLogger {
+    id: usize,
+    formatter: ConsoleFormatter,
+    buffers: Mutex<Vec<ThreadBuffer>>,
+    writer: Mutex<Box<dyn Write + Send>>,
+    flush_threshold: usize,
+    diagnostics: Diagnostics,
+}
+
+impl ShardedConsoleLogger {
+    /// Create a sharded console logger writing to stdout
+    pub fn new() -> Self {
+        Self::with_writer(std::io::stdout())
+    }
+
+    /// Create a sharded console logger writing batches of lines to `writer`
+    pub fn with_writer(writer: impl Write + Send + 'static) -> Self {
+        Self {
+            id: NEXT_LOGGER_ID.fetch_add(1, Ordering::Relaxed),
+            formatter: ConsoleFormatter::new(),
+            buffers: Mutex::new(Vec::new()),
+            writer: Mutex::new(Box::new(writer)),
+            flush_threshold: DEFAULT_THREAD_FLUSH_BYTES,
+            diagnostics: Diagnostics::new(),
+        }
+    }
+
+    /// Write a thread's buffer out once it holds `bytes` bytes
+    pub fn with_flush_threshold(mut self, bytes: usize) -> Self {
+        self.flush_threshold = bytes;
+        self
+    }
+
+    /// Include the `[timestamp]` prefix (on by default)
+    pub fn with_timestamp(mut self, enabled: bool) -> Self {
+        self.formatter.timestamps = enabled;
+        self
+    }
+
+    /// Report write failures through `diagnostics` instead of stderr
+    pub fn with_diagnostics(mut self, diagnostics: Diagnostics) -> Self {
+        self.diagnostics = diagnostics;
+        self
+    }
+
+    /// The calling thread's buffer, registered on its first record
+    ///
+    /// Returns `None` while the thread's locals are being torn down.
+    fn thread_buffer(&self) -> Option<ThreadBuffer> {
+        THREAD_BUFFERS
+            .try_with(|buffers| {
+                let mut buffers = buffers.borrow_mut();
+                if let Some((_, buffer)) = buffers.iter().find(|(id, _)| *id == self.id) {
+                    return buffer.clone();
+                }
+                // Forget buffers of loggers that have since been dropped
+                buffers.retain(|(_, buffer)| Arc::strong_count(buffer) > 1);
+                let buffer = ThreadBuffer::default();
+                self.buffers
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .push(buffer.clone());
+                buffers.push((self.id, buffer.clone()));
+                buffer
+            })
+            .ok()
+    }
+
+    fn write_out(&self, batch: &[u8]) {
+        if batch.is_empty() {
+            return;
+        }
+        let written = self
+            .writer
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .write_all(batch);
+        if let Err(err) = written {
+            self.diagnostics.report(ISSUE_SINK_WRITE_FAILED, err);
+        }
+    }
+}
+
+impl Default for ShardedConsoleLogger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Logger for ShardedConsoleLogger {
+    fn log(&self, record: &LogRecord) {
+        let mut line = self.formatter.format(record);
+        line.push(b'\n');
+        let Some(buffer) = self.thread_buffer() else {
+            self.write_out(&line);
+            return;
+        };
+        let mut pending = buffer.lock().unwrap_or_else(|e| e.into_inner());
+        pending.extend_from_slice(&line);
+        if pending.len() >= self.flush_threshold {
+            // Written under the buffer's lock, so a concurrent flush cannot
+            // put this thread's later lines ahead of these
+            self.write_out(&pending);
+            pending.clear();
+        }
+    }
+
+    /// Write out every thread's buffered lines, then flush the writer
+    fn flush(&self) {
+        self.buffers
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .retain(|buffer| {
+                let mut pending = buffer.lock().unwrap_or_else(|e| e.into_inner());
+                self.write_out(&pending);
+                pending.clear();
+                // Only this logger still holds the buffer of a thread that has exited
+                Arc::strong_count(buffer) > 1
+            });
+        let flushed = self
+            .writer
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .flush();
+        if let Err(err) = flushed {
+            self.diagnostics.report(ISSUE_SINK_WRITE_FAILED, err);
+        }
+    }
+}
+
+impl Drop for ShardedConsoleLogger {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
@@ -0,0 +1,104 @@
+//! Formatter-plus-writer logger
+//!
+//! Pairs any [`Formatter`] with any [`Write`] destination so output formats
+//! and destinations can be combined freely.
+
+use super::Logger;
+use crate::diagnostics::{Diagnostics, ISSUE_SINK_WRITE_FAILED};
+use crate::format::{Formatter, LineEnding};
+use crate::record::LogRecord;
+use std::io::Write;
+use std::sync::Mutex;
+
+/// Adapter - Writes records rendered by `F` to `W`, one per line
+///
+/// Each record is rendered together with its terminator into one buffer and
+/// handed to the writer in a single `write_all` while the sink's lock is
+/// held, so concurrent records never interleave within a line. A batch is
+/// rendered into one buffer and written with a single `write_all`, so a
+/// stdout writer takes the stdout lock once per batch rather than once per
+/// record. Write failures are reported as throttled diagnostics.
+pub struct SinkLogger<F: Formatter, W: Write> {
+    formatter: F,
+    writer: Mutex<W>,
+    line_ending: LineEnding,
+    diagnostics: Diagnostics,
+}
+
+impl<F: Formatter, W: Write> SinkLogger<F, W> {
+    /// Create a sink writing `formatter` output to `writer`
+    pub fn new(formatter: F, writer: W) -> Self {
+        Self {
+            formatter,
+            writer: Mutex::new(writer),
+            line_ending: LineEnding::default(),
+            diagnostics: Diagnostics::new(),
+        }
+    }
+
+    /// Report write failures through `diagnostics` instead of stderr
+    pub fn with_diagnostics(mut self, diagnostics: Diagnostics) -> Self {
+        self.diagnostics = diagnostics;
+        self
+    }
+
+    /// Set the terminator written after each record (defaults to `\n`)
+    pub fn with_line_ending(mut self, line_ending: LineEnding) -> Self {
+        self.line_ending = line_ending;
+        self
+    }
+
+    /// Get the formatter
+    pub fn formatter(&self) -> &F {
+        &self.formatter
+    }
+
+    /// Get the formatter for reconfiguration
+    pub fn formatter_mut(&mut self) -> &mut F {
+        &mut self.formatter
+    }
+
+    /// Consume the sink and return its writer
+    pub fn into_writer(self) -> W {
+        self.writer.into_inner().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn render(&self, record: &LogRecord, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(&self.formatter.format(record));
+        buffer.extend_from_slice(self.line_ending.as_str().as_bytes());
+    }
+
+    fn write_rendered(&self, buffer: &[u8]) {
+        let mut writer = self.writer.lock().unwrap_or_else(|e| e.into_inner());
+        if let Err(err) = writer.write_all(buffer) {
+            self.diagnostics.report(ISSUE_SINK_WRITE_FAILED, err);
+        }
+    }
+}
+
+impl<F: Formatter, W: Write> Logger for SinkLogger<F, W> {
+    fn log(&self, record: &LogRecord) {
+        let mut line = Vec::new();
+        self.render(record, &mut line);
+        self.write_rendered(&line);
+    }
+
+    fn log_batch(&self, records: &[LogRecord]) {
+        if records.is_empty() {
+            return;
+        }
+        let mut batch = Vec::new();
+        for record in records {
+            self.render(record, &mut batch);
+        }
+        self.write_rendered(&batch);
+    }
+
+    fn flush(&self) {
+        let _ = self
+            .writer
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .flush();
+    }
+}
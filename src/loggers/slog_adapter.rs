@@ -0,0 +1,106 @@
+//! slog adapter
+//!
+//! Feeds TYL records into an existing `slog::Logger`, so services built on
+//! slog can adopt TYL call sites without replacing their drains.
+
+use super::Logger;
+use crate::record::{LogLevel, LogRecord};
+use serde_json::Value;
+
+/// Adapter - Forwards records to a wrapped `slog::Logger`
+///
+/// Levels map one-to-one (`Warn` becomes `slog::Level::Warning` and `Fatal`
+/// becomes `slog::Level::Critical`). The request
+/// ID, tags, and fields become slog key/value pairs: strings, numbers, and
+/// booleans keep their type, `null` is emitted as unit, and arrays and
+/// objects are emitted as JSON text.
+pub struct SlogAdapter {
+    logger: slog::Logger,
+}
+
+impl SlogAdapter {
+    /// Wrap an existing slog logger
+    pub fn new(logger: slog::Logger) -> Self {
+        Self { logger }
+    }
+
+    /// Get the wrapped slog logger
+    pub fn inner(&self) -> &slog::Logger {
+        &self.logger
+    }
+}
+
+/// Map a TYL level to the matching slog level
+pub fn to_slog_level(level: LogLevel) -> slog::Level {
+    match level {
+        LogLevel::Trace => slog::Level::Trace,
+        LogLevel::Debug => slog::Level::Debug,
+        LogLevel::Info => slog::Level::Info,
+        LogLevel::Warn => slog::Level::Warning,
+        LogLevel::Error => slog::Level::Error,
+        LogLevel::Fatal => slog::Level::Critical,
+    }
+}
+
+struct RecordKv<'a>(&'a LogRecord);
+
+impl slog::KV for RecordKv<'_> {
+    fn serialize(
+        &self,
+        _: &slog::Record<'_>,
+        serializer: &mut dyn slog::Serializer,
+    ) -> slog::Result {
+        let record = self.0;
+        if let Some(request_id) = record.request_id() {
+            serializer.emit_str("request_id".into(), request_id)?;
+        }
+        if !record.tags().is_empty() {
+            serializer.emit_str("tags".into(), &record.tags().join(","))?;
+        }
+        for (key, value) in record.fields() {
+            let key = slog::Key::from(key.clone());
+            match value {
+                Value::Null => serializer.emit_unit(key)?,
+                Value::Bool(b) => serializer.emit_bool(key, *b)?,
+                Value::String(s) => serializer.emit_str(key, s)?,
+                Value::Number(n) => {
+                    if let Some(i) = n.as_i64() {
+                        serializer.emit_i64(key, i)?
+                    } else if let Some(u) = n.as_u64() {
+                        serializer.emit_u64(key, u)?
+                    } else {
+                        serializer.emit_f64(key, n.as_f64().unwrap_or_default())?
+                    }
+                }
+                other => serializer.emit_str(key, &other.to_string())?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Logger for SlogAdapter {
+    fn log(&self, record: &LogRecord) {
+        let kv = RecordKv(record);
+        let message = record.message();
+        // slog records need a static level, so each arm instantiates its own
+        macro_rules! emit {
+            ($level:expr) => {
+                self.logger.log(&slog::record!(
+                    $level,
+                    "",
+                    &format_args!("{}", message),
+                    slog::BorrowedKV(&kv)
+                ))
+            };
+        }
+        match to_slog_level(record.level()) {
+            slog::Level::Trace => emit!(slog::Level::Trace),
+            slog::Level::Debug => emit!(slog::Level::Debug),
+            slog::Level::Info => emit!(slog::Level::Info),
+            slog::Level::Warning => emit!(slog::Level::Warning),
+            slog::Level::Error => emit!(slog::Level::Error),
+            slog::Level::Critical => emit!(slog::Level::Critical),
+        }
+    }
+}
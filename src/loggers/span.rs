@@ -0,0 +1,152 @@
+//! Span logger
+//!
+//! Lightweight in-process spans: nested units of work that log when they
+//! start and finish, linked to their parent through a thread-local stack.
+
+use super::Logger;
+use crate::record::{LogLevel, LogRecord};
+use crate::utils::generate_span_id;
+use std::cell::RefCell;
+use std::time::Instant;
+
+thread_local! {
+    static SPAN_STACK: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Message of the record logged when a span starts
+pub const SPAN_START_MESSAGE: &str = "span_start";
+
+/// Message of the record logged when a span ends
+pub const SPAN_END_MESSAGE: &str = "span_end";
+
+/// Adapter - Tracks spans per thread and tags records with the current span
+///
+/// [`start_span`](Self::start_span) logs a `"span_start"` record with
+/// `span_name`, `span_id` and, when nested, `parent_span_id`; dropping the
+/// returned [`SpanGuard`] logs the matching `"span_end"` record with
+/// `duration_ms`. Other records logged through this logger while a span is
+/// open get its `span_id`, unless they already carry one.
+///
+/// ```rust
+/// use tyl_logging::{ConsoleLogger, SpanLogger};
+///
+/// let logger = SpanLogger::new(ConsoleLogger::new());
+/// let request = logger.start_span("handle_request");
+/// {
+///     let _query = logger.start_span("db_query");
+/// }
+/// drop(request);
+/// ```
+pub struct SpanLogger<L: Logger> {
+    inner: L,
+}
+
+impl<L: Logger> SpanLogger<L> {
+    /// Wrap `inner`
+    pub fn new(inner: L) -> Self {
+        Self { inner }
+    }
+
+    /// Get the wrapped logger
+    pub fn inner(&self) -> &L {
+        &self.inner
+    }
+
+    /// ID of the innermost open span on the current thread
+    pub fn current_span_id() -> Option<String> {
+        SPAN_STACK.with(|stack| stack.borrow().last().cloned())
+    }
+
+    /// Open a span as a child of the current thread's innermost span
+    pub fn start_span(&self, name: impl Into<String>) -> SpanGuard<'_, L> {
+        let name = name.into();
+        let id = generate_span_id();
+        let parent_id = Self::current_span_id();
+        SPAN_STACK.with(|stack| stack.borrow_mut().push(id.clone()));
+
+        let mut record = LogRecord::new(LogLevel::Info, SPAN_START_MESSAGE);
+        add_span_fields(&mut record, &name, &id, parent_id.as_deref());
+        self.inner.log(&record);
+
+        SpanGuard {
+            logger: self,
+            name,
+            id,
+            parent_id,
+            start: Instant::now(),
+        }
+    }
+}
+
+fn add_span_fields(record: &mut LogRecord, name: &str, id: &str, parent_id: Option<&str>) {
+    record.add_field("span_name", serde_json::json!(name));
+    record.add_field("span_id", serde_json::json!(id));
+    if let Some(parent_id) = parent_id {
+        record.add_field("parent_span_id", serde_json::json!(parent_id));
+    }
+}
+
+impl<L: Logger> Logger for SpanLogger<L> {
+    fn log(&self, record: &LogRecord) {
+        match Self::current_span_id() {
+            Some(span_id) if !record.fields().contains_key("span_id") => {
+                let mut record = record.clone();
+                record.add_field("span_id", serde_json::json!(span_id));
+                self.inner.log(&record);
+            }
+            _ => self.inner.log(record),
+        }
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+
+    fn barrier(&self) {
+        self.inner.barrier();
+    }
+}
+
+/// An open span; logs `"span_end"` with its `duration_ms` when dropped
+///
+/// Created through [`SpanLogger::start_span`]. A guard should be dropped on
+/// the thread that created it.
+#[must_use = "the span ends as soon as the guard is dropped"]
+pub struct SpanGuard<'a, L: Logger> {
+    logger: &'a SpanLogger<L>,
+    name: String,
+    id: String,
+    parent_id: Option<String>,
+    start: Instant,
+}
+
+impl<L: Logger> SpanGuard<'_, L> {
+    /// Get the span ID
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Get the parent span's ID, if this span is nested
+    pub fn parent_id(&self) -> Option<&str> {
+        self.parent_id.as_deref()
+    }
+}
+
+impl<L: Logger> Drop for SpanGuard<'_, L> {
+    fn drop(&mut self) {
+        let elapsed = self.start.elapsed();
+        SPAN_STACK.with(|stack| {
+            let mut stack = stack.borrow_mut();
+            if let Some(position) = stack.iter().rposition(|id| *id == self.id) {
+                stack.remove(position);
+            }
+        });
+        let mut record = LogRecord::new(LogLevel::Info, SPAN_END_MESSAGE);
+        add_span_fields(&mut record, &self.name, &self.id, self.parent_id.as_deref());
+        record.add_field(
+            "duration_ms",
+            serde_json::json!(elapsed.as_secs_f64() * 1000.0),
+        );
+        self.logger.inner.log(&record);
+    }
+}
@@ -0,0 +1,90 @@
+//! Throughput-reporting logger
+//!
+//! Counts the records and bytes passing through and periodically reports
+//! the rate, so the logging layer's own load shows up in the logs.
+
+use super::Logger;
+use crate::format::{Formatter, JsonFormatter};
+use crate::record::{LogLevel, LogRecord};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Message of the periodic throughput report
+pub const THROUGHPUT_MESSAGE: &str = "log throughput";
+
+/// Adapter - Forwards every record and reports throughput once per interval
+///
+/// Records are counted, and sized by their JSON rendering, in atomic
+/// counters. The interval is checked lazily on each call to `log`: once it
+/// has elapsed, an `Info` `"log throughput"` record with `records`, `bytes`
+/// and `per_second` fields covering the time since the previous report is
+/// forwarded ahead of the current record. No report is emitted while
+/// nothing is logged.
+pub struct StatsLogger<L: Logger> {
+    inner: L,
+    interval: Duration,
+    sizer: JsonFormatter,
+    records: AtomicU64,
+    bytes: AtomicU64,
+    last_report: Mutex<Instant>,
+}
+
+impl<L: Logger> StatsLogger<L> {
+    /// Wrap `inner`, reporting throughput every `interval`
+    pub fn new(inner: L, interval: Duration) -> Self {
+        Self {
+            inner,
+            interval,
+            sizer: JsonFormatter::new(),
+            records: AtomicU64::new(0),
+            bytes: AtomicU64::new(0),
+            last_report: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Get the wrapped logger
+    pub fn inner(&self) -> &L {
+        &self.inner
+    }
+
+    fn take_report(&self) -> Option<LogRecord> {
+        let now = Instant::now();
+        let elapsed = {
+            let mut last_report = self.last_report.lock().unwrap_or_else(|e| e.into_inner());
+            let elapsed = now.duration_since(*last_report);
+            if elapsed < self.interval {
+                return None;
+            }
+            *last_report = now;
+            elapsed
+        };
+        let records = self.records.swap(0, Ordering::Relaxed);
+        let bytes = self.bytes.swap(0, Ordering::Relaxed);
+        let mut report = LogRecord::new(LogLevel::Info, THROUGHPUT_MESSAGE);
+        report.add_u64("records", records);
+        report.add_u64("bytes", bytes);
+        report.add_f64("per_second", records as f64 / elapsed.as_secs_f64());
+        Some(report)
+    }
+}
+
+impl<L: Logger> Logger for StatsLogger<L> {
+    fn log(&self, record: &LogRecord) {
+        self.records.fetch_add(1, Ordering::Relaxed);
+        self.bytes
+            .fetch_add(self.sizer.format(record).len() as u64, Ordering::Relaxed);
+        if let Some(report) = self.take_report() {
+            self.inner.log(&report);
+        }
+        self.inner.log(record);
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+
+    fn barrier(&self) {
+        self.inner.barrier();
+    }
+}
@@ -0,0 +1,96 @@
+//! Tenant-routing logger
+//!
+//! Sends each record to the sink registered for its tenant, so multi-tenant
+//! services can keep every tenant's logs apart.
+
+use super::Logger;
+use crate::record::LogRecord;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Routing key identifying a tenant
+pub type TenantKey = String;
+
+/// Key function reading the tenant from the field `field`
+///
+/// String values are used as-is and other scalars by their JSON text;
+/// records without the field, or with a `null`, array or object value,
+/// have no tenant.
+pub fn tenant_from_field(field: &str) -> impl Fn(&LogRecord) -> Option<TenantKey> {
+    let field = field.to_string();
+    move |record| match record.fields().get(&field)? {
+        Value::String(s) => Some(s.clone()),
+        Value::Null | Value::Array(_) | Value::Object(_) => None,
+        other => Some(other.to_string()),
+    }
+}
+
+/// Adapter - Forwards each record to the logger registered for its tenant
+///
+/// The key function picks the tenant; records with no tenant, or one
+/// without a registered logger, go to the default logger.
+///
+/// ```rust
+/// use tyl_logging::{tenant_from_field, JsonLogger, TenantRouter};
+///
+/// let router = TenantRouter::new(tenant_from_field("tenant_id"), JsonLogger::new())
+///     .with_tenant("acme", JsonLogger::with_writer(std::io::sink()));
+/// ```
+pub struct TenantRouter<F: Fn(&LogRecord) -> Option<TenantKey>> {
+    key_fn: F,
+    tenants: HashMap<TenantKey, Box<dyn Logger + Send + Sync>>,
+    default: Box<dyn Logger + Send + Sync>,
+}
+
+impl<F: Fn(&LogRecord) -> Option<TenantKey>> TenantRouter<F> {
+    /// Route by `key_fn`, sending unmatched records to `default`
+    pub fn new(key_fn: F, default: impl Logger + Send + Sync + 'static) -> Self {
+        Self {
+            key_fn,
+            tenants: HashMap::new(),
+            default: Box::new(default),
+        }
+    }
+
+    /// Register the logger for `tenant`, replacing any earlier one
+    pub fn with_tenant(
+        mut self,
+        tenant: impl Into<TenantKey>,
+        logger: impl Logger + Send + Sync + 'static,
+    ) -> Self {
+        self.tenants.insert(tenant.into(), Box::new(logger));
+        self
+    }
+
+    /// Number of registered tenants
+    pub fn tenant_count(&self) -> usize {
+        self.tenants.len()
+    }
+
+    fn route(&self, record: &LogRecord) -> &(dyn Logger + Send + Sync) {
+        (self.key_fn)(record)
+            .and_then(|tenant| self.tenants.get(&tenant))
+            .unwrap_or(&self.default)
+            .as_ref()
+    }
+}
+
+impl<F: Fn(&LogRecord) -> Option<TenantKey>> Logger for TenantRouter<F> {
+    fn log(&self, record: &LogRecord) {
+        self.route(record).log(record);
+    }
+
+    fn flush(&self) {
+        for logger in self.tenants.values() {
+            logger.flush();
+        }
+        self.default.flush();
+    }
+
+    fn barrier(&self) {
+        for logger in self.tenants.values() {
+            logger.barrier();
+        }
+        self.default.barrier();
+    }
+}
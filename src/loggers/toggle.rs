@@ -0,0 +1,69 @@
+//! Runtime on/off switch
+//!
+//! Keeps a logger in place, e.g. feature-flagged debug output, while letting
+//! it be switched off and on again without rebuilding the logger stack.
+
+use super::Logger;
+use crate::record::LogRecord;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Adapter - Forwards records only while enabled
+///
+/// Checking the switch is a single relaxed atomic load, so a disabled
+/// logger costs next to nothing.
+pub struct ToggleLogger<L: Logger> {
+    inner: L,
+    enabled: AtomicBool,
+}
+
+impl<L: Logger> ToggleLogger<L> {
+    /// Wrap `inner`, initially enabled
+    pub fn new(inner: L) -> Self {
+        Self {
+            inner,
+            enabled: AtomicBool::new(true),
+        }
+    }
+
+    /// Start forwarding records
+    pub fn enable(&self) {
+        self.enabled.store(true, Ordering::Relaxed);
+    }
+
+    /// Stop forwarding records until [`enable`](Self::enable) is called
+    pub fn disable(&self) {
+        self.enabled.store(false, Ordering::Relaxed);
+    }
+
+    /// Check whether records are being forwarded
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    /// Get the wrapped logger
+    pub fn inner(&self) -> &L {
+        &self.inner
+    }
+}
+
+impl<L: Logger> Logger for ToggleLogger<L> {
+    fn log(&self, record: &LogRecord) {
+        if self.is_enabled() {
+            self.inner.log(record);
+        }
+    }
+
+    fn log_batch(&self, records: &[LogRecord]) {
+        if self.is_enabled() {
+            self.inner.log_batch(records);
+        }
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+
+    fn barrier(&self) {
+        self.inner.barrier();
+    }
+}
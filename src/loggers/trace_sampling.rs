@@ -0,0 +1,75 @@
+//! Request-coherent sampling logger
+//!
+//! Head-based sampling that keeps or drops whole requests, so sampled
+//! traces are never missing records from the middle.
+
+use super::sampling::keep_nth;
+use super::Logger;
+use crate::record::LogRecord;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Adapter - Samples by request ID so a request is kept or dropped as a unit
+///
+/// The decision for a record carrying a request ID is a pure function of
+/// that ID: its FNV-1a hash is mapped onto `0.0..1.0` and compared with the
+/// rate. Every record of a request therefore shares one decision, across
+/// threads and processes alike. Records without a request ID fall back to
+/// the per-record sampling of [`SamplingLogger`](super::SamplingLogger).
+pub struct TraceSamplingLogger<L: Logger> {
+    inner: L,
+    rate: f64,
+    seen: AtomicU64,
+}
+
+impl<L: Logger> TraceSamplingLogger<L> {
+    /// Wrap `inner`, keeping `rate` (clamped to `0.0..=1.0`) of requests
+    pub fn new(inner: L, rate: f64) -> Self {
+        Self {
+            inner,
+            rate: rate.clamp(0.0, 1.0),
+            seen: AtomicU64::new(0),
+        }
+    }
+
+    /// Get the sample rate
+    pub fn rate(&self) -> f64 {
+        self.rate
+    }
+
+    /// Get the wrapped logger
+    pub fn inner(&self) -> &L {
+        &self.inner
+    }
+
+    /// Whether records carrying `request_id` are kept
+    pub fn keeps_request(&self, request_id: &str) -> bool {
+        let position = fnv1a(request_id.as_bytes()) as f64 / u64::MAX as f64;
+        position < self.rate
+    }
+}
+
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+impl<L: Logger> Logger for TraceSamplingLogger<L> {
+    fn log(&self, record: &LogRecord) {
+        let keep = match record.request_id() {
+            Some(request_id) => self.keeps_request(request_id),
+            None => keep_nth(self.seen.fetch_add(1, Ordering::Relaxed), self.rate),
+        };
+        if keep {
+            self.inner.log(record);
+        }
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+
+    fn barrier(&self) {
+        self.inner.barrier();
+    }
+}
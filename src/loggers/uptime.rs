@@ -0,0 +1,49 @@
+//! Uptime-enriching logger
+//!
+//! Tags each record with the milliseconds elapsed since the logger was
+//! created, which is handy for boot diagnostics where wall-clock time is
+//! less useful than relative time.
+
+use super::Logger;
+use crate::record::LogRecord;
+use std::time::Instant;
+
+/// Adapter - Adds an `uptime_ms` field measured from construction
+pub struct UptimeLogger<L: Logger> {
+    inner: L,
+    start: Instant,
+}
+
+impl<L: Logger> UptimeLogger<L> {
+    /// Wrap `inner`, starting the uptime clock now
+    pub fn new(inner: L) -> Self {
+        Self {
+            inner,
+            start: Instant::now(),
+        }
+    }
+
+    /// Get the wrapped logger
+    pub fn inner(&self) -> &L {
+        &self.inner
+    }
+}
+
+impl<L: Logger> Logger for UptimeLogger<L> {
+    fn log(&self, record: &LogRecord) {
+        let uptime_ms = self.start.elapsed().as_millis() as u64;
+        let mut record = record.clone();
+        record
+            .fields_mut()
+            .insert("uptime_ms".to_string(), serde_json::json!(uptime_ms));
+        self.inner.log(&record);
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+
+    fn barrier(&self) {
+        self.inner.barrier();
+    }
+}
@@ -0,0 +1,92 @@
+//! Logging macros
+//!
+//! Shorthand for building a record and logging it in one call. Fields can
+//! be taken from any `Serialize` struct with `@flatten`:
+//!
+//! ```rust
+//! use tyl_logging::{tyl_info, ConsoleLogger};
+//!
+//! #[derive(serde::Serialize)]
+//! struct Order {
+//!     id: u64,
+//!     total: f64,
+//! }
+//!
+//! let logger = ConsoleLogger::new();
+//! let order = Order { id: 7, total: 19.5 };
+//! tyl_info!(logger, "order placed", @flatten order);
+//! ```
+
+/// Log `message` at `level`, flattening any `@flatten value` into fields
+///
+/// Each `@flatten` value is added with
+/// [`LogRecord::add_object`](crate::LogRecord::add_object); a value that
+/// does not serialize to an object contributes no fields, and the record
+/// is still logged.
+#[macro_export]
+macro_rules! tyl_log {
+    ($logger:expr, $level:expr, $message:expr $(, $($fields:tt)*)?) => {{
+        #[allow(unused_mut)]
+        let mut record = $crate::LogRecord::new($level, $message);
+        $crate::__tyl_fields!(record; $($($fields)*)?);
+        $crate::Logger::log(&$logger, &record);
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tyl_fields {
+    ($record:ident;) => {};
+    ($record:ident; @flatten $value:expr $(, $($rest:tt)*)?) => {
+        let _ = $record.add_object(&$value);
+        $crate::__tyl_fields!($record; $($($rest)*)?);
+    };
+}
+
+/// Log at `Trace`; see [`tyl_log!`](crate::tyl_log)
+#[macro_export]
+macro_rules! tyl_trace {
+    ($logger:expr, $($rest:tt)+) => {
+        $crate::tyl_log!($logger, $crate::LogLevel::Trace, $($rest)+)
+    };
+}
+
+/// Log at `Debug`; see [`tyl_log!`](crate::tyl_log)
+#[macro_export]
+macro_rules! tyl_debug {
+    ($logger:expr, $($rest:tt)+) => {
+        $crate::tyl_log!($logger, $crate::LogLevel::Debug, $($rest)+)
+    };
+}
+
+/// Log at `Info`; see [`tyl_log!`](crate::tyl_log)
+#[macro_export]
+macro_rules! tyl_info {
+    ($logger:expr, $($rest:tt)+) => {
+        $crate::tyl_log!($logger, $crate::LogLevel::Info, $($rest)+)
+    };
+}
+
+/// Log at `Warn`; see [`tyl_log!`](crate::tyl_log)
+#[macro_export]
+macro_rules! tyl_warn {
+    ($logger:expr, $($rest:tt)+) => {
+        $crate::tyl_log!($logger, $crate::LogLevel::Warn, $($rest)+)
+    };
+}
+
+/// Log at `Error`; see [`tyl_log!`](crate::tyl_log)
+#[macro_export]
+macro_rules! tyl_error {
+    ($logger:expr, $($rest:tt)+) => {
+        $crate::tyl_log!($logger, $crate::LogLevel::Error, $($rest)+)
+    };
+}
+
+/// Log at `Fatal`; see [`tyl_log!`](crate::tyl_log)
+#[macro_export]
+macro_rules! tyl_fatal {
+    ($logger:expr, $($rest:tt)+) => {
+        $crate::tyl_log!($logger, $crate::LogLevel::Fatal, $($rest)+)
+    };
+}
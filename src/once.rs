@@ -0,0 +1,49 @@
+//! Log-once support
+//!
+//! Process-wide deduplication for messages such as deprecation warnings and
+//! startup notices that should appear a single time no matter how often the
+//! code path runs.
+
+use crate::loggers::Logger;
+use crate::record::LogRecord;
+use std::collections::HashSet;
+use std::sync::{Mutex, OnceLock};
+
+static SEEN_KEYS: OnceLock<Mutex<HashSet<&'static str>>> = OnceLock::new();
+
+/// Log `record` only the first time `key` is seen in this process
+///
+/// Returns `true` if the record was emitted. The [`tyl_warn_once!`](crate::tyl_warn_once)
+/// macro derives the key from the call site.
+pub fn log_once<L: Logger + ?Sized>(logger: &L, key: &'static str, record: &LogRecord) -> bool {
+    let first = SEEN_KEYS
+        .get_or_init(|| Mutex::new(HashSet::new()))
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .insert(key);
+    if first {
+        logger.log(record);
+    }
+    first
+}
+
+/// Log a formatted `Warn` record once per call site
+///
+/// ```rust
+/// use tyl_logging::{tyl_warn_once, ConsoleLogger};
+///
+/// let logger = ConsoleLogger::new();
+/// for _ in 0..3 {
+///     tyl_warn_once!(logger, "{} is deprecated", "old_api");
+/// }
+/// ```
+#[macro_export]
+macro_rules! tyl_warn_once {
+    ($logger:expr, $($arg:tt)+) => {
+        $crate::log_once(
+            &$logger,
+            concat!(module_path!(), ":", line!(), ":", column!()),
+            &$crate::LogRecord::new($crate::LogLevel::Warn, format!($($arg)+)),
+        )
+    };
+}
@@ -0,0 +1,67 @@
+//! Panic capture
+//!
+//! Turns panics into structured `Error` records. The thread name, source
+//! location, and payload are kept as separate fields so they can be queried
+//! instead of parsed out of a single message string.
+
+use crate::global::SharedLogger;
+use crate::record::{LogLevel, LogRecord};
+use crate::utils::current_thread_label;
+use serde_json::Value;
+use std::any::Any;
+use std::panic::Location;
+
+/// Message used when the panic payload is not a string
+pub const NON_STRING_PANIC_MESSAGE: &str = "panic with non-string payload";
+
+/// Build an `Error` record describing a panic
+///
+/// `&str` and `String` payloads become the message. A `serde_json::Value`
+/// object payload (e.g. from `std::panic::panic_any(json!({...}))`) is
+/// recognized and its entries are added as fields. The record always carries
+/// `thread` (see [`current_thread_label`]), `location`
+/// (`file:line`, when known), and `payload`.
+pub fn panic_record(payload: &(dyn Any + Send), location: Option<&Location<'_>>) -> LogRecord {
+    let text = payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned());
+
+    let mut record = LogRecord::new(
+        LogLevel::Error,
+        text.as_deref().unwrap_or(NON_STRING_PANIC_MESSAGE),
+    );
+
+    if let Some(Value::Object(map)) = payload.downcast_ref::<Value>() {
+        for (key, value) in map {
+            record.add_field(key, value.clone());
+        }
+    }
+
+    record.add_field("thread", Value::from(current_thread_label()));
+    if let Some(location) = location {
+        record.add_field(
+            "location",
+            Value::from(format!("{}:{}", location.file(), location.line())),
+        );
+    }
+    let payload_value = match (text, payload.downcast_ref::<Value>()) {
+        (Some(text), _) => Value::from(text),
+        (None, Some(value)) => value.clone(),
+        (None, None) => Value::Null,
+    };
+    record.add_field("payload", payload_value);
+    record
+}
+
+/// Install a panic hook that logs each panic through `logger`
+///
+/// The previously installed hook still runs afterwards, so the default
+/// stderr report is preserved.
+pub fn install_panic_hook(logger: SharedLogger) {
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        logger.log(&panic_record(info.payload(), info.location()));
+        previous(info);
+    }));
+}
@@ -0,0 +1,99 @@
+//! Logger pipeline builder
+//!
+//! Composes the wrapping loggers in a fixed, documented order so call sites
+//! don't have to nest them by hand.
+
+use crate::loggers::{
+    DedupLogger, EnrichingLogger, LevelFilterLogger, Logger, RedactingLogger, SamplingLogger,
+};
+use crate::record::LogLevel;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Builder for a stack of wrapping loggers ending in a sink
+///
+/// Regardless of the order the builder methods are called in, records flow
+/// through the stages in this order:
+///
+/// 1. `filter` - drop records below the minimum level
+/// 2. `enrich` - add static fields (explicit record fields win)
+/// 3. `redact` - mask sensitive field values, including enriched ones
+/// 4. `sample` - keep a fraction of what is left
+/// 5. `dedup` - drop consecutive duplicates among sampled records
+/// 6. `sink` - the final logger
+///
+/// ```rust
+/// use tyl_logging::{JsonLogger, LogLevel, LoggerPipeline};
+///
+/// let logger = LoggerPipeline::new()
+///     .filter(LogLevel::Info)
+///     .redact(["password"])
+///     .sink(JsonLogger::new());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct LoggerPipeline {
+    min_level: Option<LogLevel>,
+    fields: HashMap<String, Value>,
+    redact_keys: Vec<String>,
+    sample_rate: Option<f64>,
+    dedup: bool,
+}
+
+impl LoggerPipeline {
+    /// Start an empty pipeline
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Drop records below `min_level`
+    pub fn filter(mut self, min_level: LogLevel) -> Self {
+        self.min_level = Some(min_level);
+        self
+    }
+
+    /// Add static fields to every record
+    pub fn enrich<K: Into<String>>(mut self, fields: impl IntoIterator<Item = (K, Value)>) -> Self {
+        self.fields
+            .extend(fields.into_iter().map(|(k, v)| (k.into(), v)));
+        self
+    }
+
+    /// Mask the values of these field keys
+    pub fn redact<K: Into<String>>(mut self, keys: impl IntoIterator<Item = K>) -> Self {
+        self.redact_keys.extend(keys.into_iter().map(Into::into));
+        self
+    }
+
+    /// Keep only `rate` of the records (see [`SamplingLogger`])
+    pub fn sample(mut self, rate: f64) -> Self {
+        self.sample_rate = Some(rate);
+        self
+    }
+
+    /// Drop consecutive duplicate records
+    pub fn dedup(mut self) -> Self {
+        self.dedup = true;
+        self
+    }
+
+    /// Finish the pipeline with `sink` as the final logger
+    pub fn sink(self, sink: impl Logger + Send + Sync + 'static) -> Box<dyn Logger + Send + Sync> {
+        let mut logger: Box<dyn Logger + Send + Sync> = Box::new(sink);
+        if self.dedup {
+            logger = Box::new(DedupLogger::new(logger));
+        }
+        if let Some(rate) = self.sample_rate {
+            logger = Box::new(SamplingLogger::new(logger, rate));
+        }
+        if !self.redact_keys.is_empty() {
+            logger = Box::new(RedactingLogger::new(logger, self.redact_keys));
+        }
+        if !self.fields.is_empty() {
+            logger = Box::new(EnrichingLogger::new(logger, self.fields));
+        }
+        if let Some(min_level) = self.min_level {
+            logger = Box::new(LevelFilterLogger::new(logger, min_level));
+        }
+        logger
+    }
+}
@@ -3,13 +3,26 @@
 //! This module contains the core logging data structures including
 //! log levels, records, and related helper functions.
 
+use crate::fields::{FieldCollisionPolicy, FieldKeyValidator, NoneFieldPolicy};
+use crate::utils::{encode_base64, format_level};
+use crate::LoggingResult;
 use serde_json::Value;
+use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::time::{SystemTime, UNIX_EPOCH};
+use tyl_errors::TylError;
+
+/// Suffix appended to messages cut short by [`LogRecord::with_max_message_len`]
+pub const TRUNCATION_SUFFIX: &str = "…(truncated)";
+
+/// Bytes [`LogRecord::add_bytes_base64`] encodes before truncating
+pub const DEFAULT_MAX_BASE64_BYTES: usize = 1024;
 
 /// Log severity levels in order of importance
+///
+/// Variants are ordered by severity; `Fatal` is the highest.
 #[derive(
-    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+    Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
 )]
 pub enum LogLevel {
     Trace = 0,
@@ -17,6 +30,39 @@ pub enum LogLevel {
     Info = 2,
     Warn = 3,
     Error = 4,
+    /// The process is about to abort
+    Fatal = 5,
+}
+
+impl LogLevel {
+    /// Every level, from least to most severe
+    pub const fn all() -> [LogLevel; 6] {
+        [
+            LogLevel::Trace,
+            LogLevel::Debug,
+            LogLevel::Info,
+            LogLevel::Warn,
+            LogLevel::Error,
+            LogLevel::Fatal,
+        ]
+    }
+}
+
+impl std::str::FromStr for LogLevel {
+    type Err = TylError;
+
+    /// Parse a level name case-insensitively; `WARNING` is accepted for `Warn`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "TRACE" => Ok(LogLevel::Trace),
+            "DEBUG" => Ok(LogLevel::Debug),
+            "INFO" => Ok(LogLevel::Info),
+            "WARN" | "WARNING" => Ok(LogLevel::Warn),
+            "ERROR" => Ok(LogLevel::Error),
+            "FATAL" => Ok(LogLevel::Fatal),
+            _ => Err(TylError::configuration(format!("invalid log level: {s}"))),
+        }
+    }
 }
 
 /// A structured log record containing all log information
@@ -27,6 +73,10 @@ pub struct LogRecord {
     timestamp: u64,
     fields: HashMap<String, Value>,
     request_id: Option<String>,
+    tags: Vec<String>,
+    level_pinned: bool,
+    collision_policy: FieldCollisionPolicy,
+    none_policy: NoneFieldPolicy,
 }
 
 impl LogRecord {
@@ -41,20 +91,115 @@ impl LogRecord {
                 .as_secs(),
             fields: HashMap::new(),
             request_id: None,
+            tags: Vec::new(),
+            level_pinned: false,
+            collision_policy: FieldCollisionPolicy::default(),
+            none_policy: NoneFieldPolicy::default(),
         }
     }
 
+    /// Override the captured timestamp, e.g. when replaying historical events
+    ///
+    /// Uses the same unit as [`LogRecord::timestamp`]: seconds since the Unix epoch.
+    pub fn with_timestamp(mut self, timestamp: u64) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+
+    /// Build an `Error` record from a [`TylError`]
+    ///
+    /// The message is the error's display text. The record carries
+    /// `error_kind` (the snake_case error variant, e.g. `"validation"`) and
+    /// `error_detail` (the full debug form, which includes any structured
+    /// context such as the offending field).
+    pub fn from_tyl_error(err: &TylError) -> Self {
+        let detail = format!("{err:?}");
+        let mut record = Self::new(LogLevel::Error, err.to_string());
+        record.add_field("error_kind", Value::String(error_kind(&detail)));
+        record.add_field("error_detail", Value::String(detail));
+        record
+    }
+
+    /// Truncate the message to at most `max_len` bytes, appending [`TRUNCATION_SUFFIX`]
+    ///
+    /// The cut is moved back to the nearest character boundary so the message
+    /// stays valid UTF-8. Messages within the limit are left untouched.
+    pub fn with_max_message_len(mut self, max_len: usize) -> Self {
+        if self.message.len() > max_len {
+            let kept = crate::utils::truncate_utf8(&self.message, max_len).len();
+            self.message.truncate(kept);
+            self.message.push_str(TRUNCATION_SUFFIX);
+        }
+        self
+    }
+
     /// Get the log level
     pub fn level(&self) -> LogLevel {
         self.level
     }
 
+    /// Add a `thread` field naming the current thread
+    ///
+    /// Uses the thread's name, falling back to its id for unnamed threads.
+    pub fn with_thread_info(mut self) -> Self {
+        self.fields.insert(
+            "thread".to_string(),
+            Value::String(crate::utils::current_thread_label()),
+        );
+        self
+    }
+
+    /// Set the level explicitly
+    ///
+    /// An explicitly set level is kept by [`LogRecord::with_outcome`].
+    pub fn with_level(mut self, level: LogLevel) -> Self {
+        self.level = level;
+        self.level_pinned = true;
+        self
+    }
+
+    /// Copy this record at a different level
+    ///
+    /// Message, fields, timestamp, request ID and tags are kept. Like
+    /// [`LogRecord::with_level`], the new level is kept by
+    /// [`LogRecord::with_outcome`].
+    pub fn clone_with_level(&self, level: LogLevel) -> Self {
+        self.clone().with_level(level)
+    }
+
+    /// Record whether the operation succeeded in a boolean `success` field
+    ///
+    /// Unless the level was set with [`LogRecord::with_level`], the level
+    /// becomes `Info` on success and `Error` on failure.
+    pub fn with_outcome(mut self, success: bool) -> Self {
+        self.add_bool("success", success);
+        if !self.level_pinned {
+            self.level = if success {
+                LogLevel::Info
+            } else {
+                LogLevel::Error
+            };
+        }
+        self
+    }
+
+    /// Change the log level in place
+    pub(crate) fn set_level(&mut self, level: LogLevel) {
+        self.level = level;
+    }
+
+    /// Replace the message in place
+    #[cfg_attr(not(feature = "regex"), allow(dead_code))]
+    pub(crate) fn set_message(&mut self, message: String) {
+        self.message = message;
+    }
+
     /// Get the log message
     pub fn message(&self) -> &str {
         &self.message
     }
 
-    /// Get the timestamp
+    /// Get the timestamp in seconds since the Unix epoch
     pub fn timestamp(&self) -> u64 {
         self.timestamp
     }
@@ -64,9 +209,280 @@ impl LogRecord {
         &self.fields
     }
 
+    /// Get the additional fields for in-place rewriting
+    pub(crate) fn fields_mut(&mut self) -> &mut HashMap<String, Value> {
+        &mut self.fields
+    }
+
+    /// Choose how field key collisions are handled (defaults to overwrite)
+    pub fn with_collision_policy(mut self, policy: FieldCollisionPolicy) -> Self {
+        self.collision_policy = policy;
+        self
+    }
+
+    /// Get the field collision policy
+    pub fn collision_policy(&self) -> FieldCollisionPolicy {
+        self.collision_policy
+    }
+
+    /// Choose what [`LogRecord::add_option`] does with `None` (defaults to `null`)
+    pub fn with_none_policy(mut self, policy: NoneFieldPolicy) -> Self {
+        self.none_policy = policy;
+        self
+    }
+
+    /// Get the policy for `None` optional fields
+    pub fn none_policy(&self) -> NoneFieldPolicy {
+        self.none_policy
+    }
+
     /// Add a field to the log record
+    ///
+    /// A key collision is resolved by the record's [`FieldCollisionPolicy`];
+    /// under [`FieldCollisionPolicy::Error`] the new value is discarded.
+    /// Use [`LogRecord::try_add_field`] to observe collisions.
     pub fn add_field(&mut self, key: impl Into<String>, value: Value) {
-        self.fields.insert(key.into(), value);
+        let _ = self.try_add_field(key, value);
+    }
+
+    /// Add a field, returning the key it was stored under
+    ///
+    /// Returns an error if the key is already present and the record's
+    /// policy is [`FieldCollisionPolicy::Error`]; the record is then left
+    /// unchanged. Under [`FieldCollisionPolicy::KeepFirst`] a colliding
+    /// value is discarded and the existing key returned.
+    pub fn try_add_field(&mut self, key: impl Into<String>, value: Value) -> LoggingResult<String> {
+        let key = key.into();
+        if !self.fields.contains_key(&key) {
+            self.fields.insert(key.clone(), value);
+            return Ok(key);
+        }
+        match self.collision_policy {
+            FieldCollisionPolicy::Overwrite => {
+                self.fields.insert(key.clone(), value);
+                Ok(key)
+            }
+            FieldCollisionPolicy::KeepFirst => Ok(key),
+            FieldCollisionPolicy::RenameWithSuffix => {
+                let renamed = (2..)
+                    .map(|n| format!("{key}_{n}"))
+                    .find(|candidate| !self.fields.contains_key(candidate))
+                    .expect("an unused suffix exists");
+                self.fields.insert(renamed.clone(), value);
+                Ok(renamed)
+            }
+            FieldCollisionPolicy::Error => Err(TylError::validation(
+                key,
+                "field already present on this record",
+            )),
+        }
+    }
+
+    /// Append `value` to the array stored under `key`
+    ///
+    /// A missing key becomes a one-element array, an existing array is
+    /// pushed to, and an existing scalar or object is promoted to a
+    /// two-element array holding the old and new values.
+    pub fn append_field(&mut self, key: impl Into<String>, value: Value) {
+        match self.fields.entry(key.into()) {
+            Entry::Vacant(entry) => {
+                entry.insert(Value::Array(vec![value]));
+            }
+            Entry::Occupied(mut entry) => match entry.get_mut() {
+                Value::Array(items) => items.push(value),
+                existing => {
+                    let previous = existing.take();
+                    *existing = Value::Array(vec![previous, value]);
+                }
+            },
+        }
+    }
+
+    /// Add a signed integer field
+    pub fn add_i64(&mut self, key: impl Into<String>, value: i64) {
+        self.add_field(key, Value::from(value));
+    }
+
+    /// Add an unsigned integer field
+    pub fn add_u64(&mut self, key: impl Into<String>, value: u64) {
+        self.add_field(key, Value::from(value));
+    }
+
+    /// Add a floating point field
+    ///
+    /// JSON has no representation for NaN or infinity; those are stored as `null`.
+    pub fn add_f64(&mut self, key: impl Into<String>, value: f64) {
+        self.add_field(key, Value::from(value));
+    }
+
+    /// Add a boolean field
+    pub fn add_bool(&mut self, key: impl Into<String>, value: bool) {
+        self.add_field(key, Value::Bool(value));
+    }
+
+    /// Add a field holding the `Display` form of `value` as a JSON string
+    pub fn add_display<T: std::fmt::Display + ?Sized>(
+        &mut self,
+        key: impl Into<String>,
+        value: &T,
+    ) {
+        self.add_field(key, Value::String(value.to_string()));
+    }
+
+    /// Add a field holding the `Debug` form of `value` as a JSON string
+    pub fn add_debug<T: std::fmt::Debug + ?Sized>(&mut self, key: impl Into<String>, value: &T) {
+        self.add_field(key, Value::String(format!("{value:?}")));
+    }
+
+    /// Add a binary field as a standard base64 string
+    ///
+    /// At most [`DEFAULT_MAX_BASE64_BYTES`] bytes are encoded; see
+    /// [`LogRecord::add_bytes_base64_max`].
+    pub fn add_bytes_base64(&mut self, key: impl Into<String>, bytes: &[u8]) {
+        self.add_bytes_base64_max(key, bytes, DEFAULT_MAX_BASE64_BYTES);
+    }
+
+    /// Add a binary field as base64, encoding at most `max_bytes` bytes
+    ///
+    /// Longer input is cut to its first `max_bytes` bytes and
+    /// [`TRUNCATION_SUFFIX`] is appended to the encoded string.
+    pub fn add_bytes_base64_max(&mut self, key: impl Into<String>, bytes: &[u8], max_bytes: usize) {
+        let mut encoded = encode_base64(&bytes[..bytes.len().min(max_bytes)]);
+        if bytes.len() > max_bytes {
+            encoded.push_str(TRUNCATION_SUFFIX);
+        }
+        self.add_field(key, Value::String(encoded));
+    }
+
+    /// Add a measurement as a `{"value": .., "unit": ..}` object
+    ///
+    /// Gives latencies, sizes and other metric-like fields a consistent
+    /// shape with an explicit unit. A non-finite `value` is stored as `null`.
+    ///
+    /// ```rust
+    /// use tyl_logging::{LogLevel, LogRecord};
+    ///
+    /// let mut record = LogRecord::new(LogLevel::Info, "query finished");
+    /// record.add_measurement("latency", 1.5, "ms");
+    /// assert_eq!(record.fields()["latency"]["unit"], "ms");
+    /// ```
+    pub fn add_measurement(&mut self, key: impl Into<String>, value: f64, unit: &str) {
+        self.add_field(key, serde_json::json!({ "value": value, "unit": unit }));
+    }
+
+    /// Add a byte range as a `{"start": .., "end": .., "len": ..}` object
+    ///
+    /// `end` is exclusive. An inverted range, with `end` before `start`, is
+    /// stored with its bounds swapped so `len` is never negative.
+    pub fn add_range(&mut self, key: impl Into<String>, start: usize, end: usize) {
+        let (start, end) = if end < start {
+            (end, start)
+        } else {
+            (start, end)
+        };
+        self.add_field(
+            key,
+            serde_json::json!({ "start": start, "end": end, "len": end - start }),
+        );
+    }
+
+    /// Add a JSON object field built from a map's entries
+    ///
+    /// Accepts anything iterable as key/value pairs, including `&HashMap`
+    /// and `&BTreeMap`. Keys use their `Display` form; a value that fails to
+    /// serialize is stored as `null`.
+    pub fn add_map<K, V>(&mut self, key: impl Into<String>, map: impl IntoIterator<Item = (K, V)>)
+    where
+        K: std::fmt::Display,
+        V: serde::Serialize,
+    {
+        let object: serde_json::Map<String, Value> = map
+            .into_iter()
+            .map(|(k, v)| {
+                (
+                    k.to_string(),
+                    serde_json::to_value(v).unwrap_or(Value::Null),
+                )
+            })
+            .collect();
+        self.add_field(key, Value::Object(object));
+    }
+
+    /// Add an optional field: the serialized value if `Some`
+    ///
+    /// `None` becomes a JSON `null` or leaves the key out, following the
+    /// record's [`NoneFieldPolicy`]. A value that fails to serialize is
+    /// stored as `null`.
+    pub fn add_option<T: serde::Serialize>(&mut self, key: impl Into<String>, value: &Option<T>) {
+        match value {
+            Some(value) => self.add_field(key, serde_json::to_value(value).unwrap_or(Value::Null)),
+            None => match self.none_policy {
+                NoneFieldPolicy::Null => self.add_field(key, Value::Null),
+                NoneFieldPolicy::Omit => {}
+            },
+        }
+    }
+
+    /// Add each top-level key of `value`'s serialized object as a field
+    ///
+    /// Intended for structs and maps; nested values are kept as-is. Returns
+    /// a validation error, leaving the record unchanged, if `value` fails to
+    /// serialize or serializes to something other than an object.
+    pub fn add_object<T: serde::Serialize + ?Sized>(&mut self, value: &T) -> LoggingResult<()> {
+        let object = match serde_json::to_value(value) {
+            Ok(Value::Object(object)) => object,
+            Ok(other) => {
+                return Err(TylError::validation(
+                    "object",
+                    format!("expected a value serializing to an object, got {other}"),
+                ))
+            }
+            Err(err) => return Err(TylError::validation("object", err.to_string())),
+        };
+        for (key, value) in object {
+            self.add_field(key, value);
+        }
+        Ok(())
+    }
+
+    /// Add a field after checking its key with `validator`
+    ///
+    /// Under [`FieldKeyPolicy::Sanitize`](crate::fields::FieldKeyPolicy::Sanitize)
+    /// the key is rewritten and the field always added; under
+    /// [`FieldKeyPolicy::Reject`](crate::fields::FieldKeyPolicy::Reject) an
+    /// invalid key returns an error and the record is left unchanged.
+    pub fn add_validated_field(
+        &mut self,
+        validator: &FieldKeyValidator,
+        key: &str,
+        value: Value,
+    ) -> LoggingResult<()> {
+        let key = validator.validate(key)?;
+        self.try_add_field(key, value)?;
+        Ok(())
+    }
+
+    /// Add a low-cardinality tag such as `critical` or `team:payments`
+    ///
+    /// Tags are kept in insertion order; adding an existing tag is a no-op.
+    pub fn add_tag(&mut self, tag: impl Into<String>) {
+        let tag = tag.into();
+        if !self.tags.contains(&tag) {
+            self.tags.push(tag);
+        }
+    }
+
+    /// Add several tags, skipping duplicates
+    pub fn with_tags<T: Into<String>>(mut self, tags: impl IntoIterator<Item = T>) -> Self {
+        for tag in tags {
+            self.add_tag(tag);
+        }
+        self
+    }
+
+    /// Get the tags
+    pub fn tags(&self) -> &[String] {
+        &self.tags
     }
 
     /// Add a request ID to the log record
@@ -75,8 +491,72 @@ impl LogRecord {
         self
     }
 
+    /// Set the request ID from `f` unless one is already present
+    ///
+    /// `f` is only called when the record has no request ID, so no ID is
+    /// generated needlessly.
+    pub fn ensure_request_id<F: FnOnce() -> String>(&mut self, f: F) {
+        if self.request_id.is_none() {
+            self.request_id = Some(f());
+        }
+    }
+
     /// Get the request ID if present
     pub fn request_id(&self) -> Option<&str> {
         self.request_id.as_deref()
     }
+
+    /// Flatten the record into one map, for sinks that take plain key/value data
+    ///
+    /// Contains `level` (its name, e.g. `"INFO"`), `message`, `timestamp`,
+    /// `request_id` (`null` when unset), `tags` when there are any, and
+    /// every custom field. The standard keys take precedence: a custom field
+    /// sharing one of their names is kept under `fields.<name>` instead.
+    pub fn to_map(&self) -> HashMap<String, Value> {
+        let mut map = HashMap::with_capacity(self.fields.len() + 5);
+        map.insert("level".to_string(), Value::from(format_level(self.level)));
+        map.insert("message".to_string(), Value::from(self.message.as_str()));
+        map.insert("timestamp".to_string(), Value::from(self.timestamp));
+        map.insert(
+            "request_id".to_string(),
+            Value::from(self.request_id.as_deref()),
+        );
+        if !self.tags.is_empty() {
+            map.insert("tags".to_string(), Value::from(self.tags.clone()));
+        }
+        for (key, value) in &self.fields {
+            if map.contains_key(key) {
+                map.insert(format!("fields.{key}"), value.clone());
+            } else {
+                map.insert(key.clone(), value.clone());
+            }
+        }
+        map
+    }
+}
+
+impl From<&TylError> for LogRecord {
+    fn from(err: &TylError) -> Self {
+        Self::from_tyl_error(err)
+    }
+}
+
+/// Derive a snake_case kind from the variant name leading a debug string
+fn error_kind(debug: &str) -> String {
+    let variant = debug
+        .split(|c: char| !c.is_alphanumeric() && c != '_')
+        .next()
+        .unwrap_or_default();
+    let mut kind = String::with_capacity(variant.len() + 4);
+    for (i, c) in variant.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                kind.push('_');
+            }
+            kind.extend(c.to_lowercase());
+        } else {
+            kind.push(c);
+        }
+    }
+    kind
 }
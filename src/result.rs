@@ -0,0 +1,44 @@
+//! Result logging helpers
+//!
+//! Log the error side of a `Result` in passing, without breaking a `?` or
+//! combinator chain.
+
+use crate::loggers::Logger;
+use crate::record::{LogLevel, LogRecord};
+use std::error::Error;
+
+/// Log `result` if it is an `Err`, then return it unchanged
+///
+/// An `Err` is logged at `Error` with `context` as the message, the error's
+/// `Display` text in an `error` field, and the `Display` text of each of
+/// its [`source`](Error::source)s, outermost first, in an `error_chain`
+/// array. An `Ok` is passed through without logging.
+///
+/// ```rust
+/// use tyl_logging::{log_result, JsonLogger};
+///
+/// let logger = JsonLogger::new();
+/// let port = log_result(&logger, "parse port", "80a".parse::<u16>());
+/// assert!(port.is_err());
+/// ```
+pub fn log_result<T, E: Error>(
+    logger: &dyn Logger,
+    context: &str,
+    result: Result<T, E>,
+) -> Result<T, E> {
+    if let Err(err) = &result {
+        let mut record = LogRecord::new(LogLevel::Error, context);
+        record.add_display("error", err);
+        let mut chain = Vec::new();
+        let mut source = err.source();
+        while let Some(cause) = source {
+            chain.push(cause.to_string());
+            source = cause.source();
+        }
+        if !chain.is_empty() {
+            record.add_field("error_chain", serde_json::json!(chain));
+        }
+        logger.log(&record);
+    }
+    result
+}
@@ -0,0 +1,58 @@
+//! Shutdown flushing
+//!
+//! Containers receive `SIGTERM` and a short grace period before `SIGKILL`.
+//! Installing a shutdown flush makes sure buffered records reach their
+//! destination before the process exits.
+//!
+//! # Re-entrancy
+//!
+//! Nothing runs inside the signal handler itself: signal delivery only wakes
+//! a dedicated `tyl-logging-shutdown` thread, which calls
+//! [`Logger::flush`](crate::Logger::flush) and then applies the signal's
+//! default action (terminating for `SIGTERM`/`SIGINT`). Flushing can
+//! therefore take locks and do IO, but it waits for any thread currently
+//! holding the logger's output lock, and records logged after the flush has
+//! started may be lost. A second signal arriving mid-flush is handled once
+//! the first flush completes.
+
+use crate::global::SharedLogger;
+use crate::LoggingResult;
+use signal_hook::consts::{SIGINT, SIGTERM};
+use signal_hook::iterator::Signals;
+use std::os::raw::c_int;
+use tyl_errors::TylError;
+
+/// Flush `logger` when the process receives `SIGTERM` or `SIGINT`
+///
+/// ```rust,no_run
+/// use std::sync::Arc;
+/// use tyl_logging::signal::install_shutdown_flush;
+/// use tyl_logging::JsonLogger;
+///
+/// install_shutdown_flush(Arc::new(JsonLogger::new())).unwrap();
+/// ```
+pub fn install_shutdown_flush(logger: SharedLogger) -> LoggingResult<()> {
+    install_flush_on_signals(logger, &[SIGTERM, SIGINT])
+}
+
+/// Flush `logger` whenever one of `signals` is received
+///
+/// After flushing, the signal's default action is applied, so terminating
+/// signals still terminate the process.
+pub fn install_flush_on_signals(logger: SharedLogger, signals: &[c_int]) -> LoggingResult<()> {
+    let mut signals = Signals::new(signals).map_err(|err| {
+        TylError::configuration(format!("failed to register shutdown signals: {err}"))
+    })?;
+    std::thread::Builder::new()
+        .name("tyl-logging-shutdown".to_string())
+        .spawn(move || {
+            for signal in signals.forever() {
+                logger.flush();
+                let _ = signal_hook::low_level::emulate_default_handler(signal);
+            }
+        })
+        .map_err(|err| {
+            TylError::configuration(format!("failed to start shutdown flush thread: {err}"))
+        })?;
+    Ok(())
+}
@@ -0,0 +1,59 @@
+//! Startup metadata
+//!
+//! A single boot-time record describing the running process, for fleet
+//! inventory and for correlating behavior changes with deploys.
+
+use crate::loggers::Logger;
+use crate::record::{LogLevel, LogRecord};
+use serde_json::Value;
+
+/// Environment variable read for the `git_sha` field
+pub const GIT_SHA_ENV: &str = "GIT_SHA";
+
+/// Log one `Info` record describing the process at startup
+///
+/// The record (`"<service> starting"`) carries:
+/// - `service`: the given service name
+/// - `version`: the given service version
+/// - `git_sha`: the value of `GIT_SHA`, when set
+/// - `host`: the `HOSTNAME` variable, falling back to `/etc/hostname`, then `"unknown"`
+/// - `cpus`: `std::thread::available_parallelism`, when it can be determined
+///
+/// [`tyl_startup_banner!`](crate::tyl_startup_banner) passes the calling
+/// crate's `CARGO_PKG_VERSION` as the version.
+pub fn log_startup_banner<L: Logger + ?Sized>(logger: &L, service: &str, version: &str) {
+    let mut record = LogRecord::new(LogLevel::Info, format!("{service} starting"));
+    record.add_field("service", Value::from(service));
+    record.add_field("version", Value::from(version));
+    if let Ok(sha) = std::env::var(GIT_SHA_ENV) {
+        record.add_field("git_sha", Value::from(sha));
+    }
+    record.add_field("host", Value::from(hostname()));
+    if let Ok(cpus) = std::thread::available_parallelism() {
+        record.add_u64("cpus", cpus.get() as u64);
+    }
+    logger.log(&record);
+}
+
+fn hostname() -> String {
+    std::env::var("HOSTNAME")
+        .ok()
+        .or_else(|| std::fs::read_to_string("/etc/hostname").ok())
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Log the startup banner with the calling crate's package version
+///
+/// ```rust
+/// use tyl_logging::{tyl_startup_banner, JsonLogger};
+///
+/// tyl_startup_banner!(JsonLogger::new(), "billing");
+/// ```
+#[macro_export]
+macro_rules! tyl_startup_banner {
+    ($logger:expr, $service:expr) => {
+        $crate::log_startup_banner(&$logger, $service, env!("CARGO_PKG_VERSION"))
+    };
+}
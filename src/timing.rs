@@ -0,0 +1,106 @@
+//! Timing helpers
+//!
+//! This module contains RAII guards and closure wrappers that measure how
+//! long a piece of work takes and log the result when it finishes.
+
+use crate::loggers::Logger;
+use crate::record::{LogLevel, LogRecord};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+fn duration_ms(elapsed: Duration) -> Value {
+    serde_json::json!(elapsed.as_secs_f64() * 1000.0)
+}
+
+/// Run `f`, then log `message` at `level` with its `duration_ms`
+///
+/// Returns whatever `f` returns.
+pub fn log_timed<L, T, F>(logger: &L, level: LogLevel, message: &str, f: F) -> T
+where
+    L: Logger + ?Sized,
+    F: FnOnce() -> T,
+{
+    let start = Instant::now();
+    let result = f();
+    let mut record = LogRecord::new(level, message);
+    record.add_field("duration_ms", duration_ms(start.elapsed()));
+    logger.log(&record);
+    result
+}
+
+/// Run the fallible `f`, then log its outcome with `duration_ms`
+///
+/// `Ok` is logged at `level`; `Err` is logged at `Error` with the error's
+/// `Display` text in an `error` field. The result is returned unchanged.
+pub fn log_timed_result<L, T, E, F>(
+    logger: &L,
+    level: LogLevel,
+    message: &str,
+    f: F,
+) -> Result<T, E>
+where
+    L: Logger + ?Sized,
+    E: Display,
+    F: FnOnce() -> Result<T, E>,
+{
+    let start = Instant::now();
+    let result = f();
+    let elapsed = start.elapsed();
+    let mut record = match &result {
+        Ok(_) => LogRecord::new(level, message),
+        Err(err) => {
+            let mut record = LogRecord::new(LogLevel::Error, message);
+            record.add_display("error", err);
+            record
+        }
+    };
+    record.add_field("duration_ms", duration_ms(elapsed));
+    logger.log(&record);
+    result
+}
+
+/// RAII guard that logs `"<name> completed"` with a `duration_ms` field on drop
+///
+/// Created through [`LoggerExt::timed_scope`](crate::LoggerExt::timed_scope).
+pub struct TimedScope<'a, L: Logger + ?Sized> {
+    logger: &'a L,
+    name: String,
+    start: Instant,
+    fields: HashMap<String, Value>,
+}
+
+impl<'a, L: Logger + ?Sized> TimedScope<'a, L> {
+    /// Start timing a named scope
+    pub fn new(logger: &'a L, name: impl Into<String>) -> Self {
+        Self {
+            logger,
+            name: name.into(),
+            start: Instant::now(),
+            fields: HashMap::new(),
+        }
+    }
+
+    /// Add a field to the record emitted when the scope ends
+    pub fn add_field(&mut self, key: impl Into<String>, value: Value) {
+        self.fields.insert(key.into(), value);
+    }
+
+    /// Get the scope name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl<L: Logger + ?Sized> Drop for TimedScope<'_, L> {
+    fn drop(&mut self) {
+        let elapsed = self.start.elapsed();
+        let mut record = LogRecord::new(LogLevel::Info, format!("{} completed", self.name));
+        for (key, value) in self.fields.drain() {
+            record.add_field(key, value);
+        }
+        record.add_field("duration_ms", duration_ms(elapsed));
+        self.logger.log(&record);
+    }
+}
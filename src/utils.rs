@@ -10,12 +10,97 @@ pub fn generate_request_id() -> String {
     Uuid::new_v4().to_string()
 }
 
+/// Generate a new span ID: 16 lowercase hex digits
+pub fn generate_span_id() -> String {
+    let mut id = Uuid::new_v4().simple().to_string();
+    id.truncate(16);
+    id
+}
+
 /// Format a timestamp as a string
 pub fn format_timestamp(timestamp: u64) -> String {
     // Simple timestamp formatting
     format!("{timestamp}")
 }
 
+/// Format a timestamp in seconds since the Unix epoch as RFC 3339 UTC
+///
+/// For example `1700000000` becomes `"2023-11-14T22:13:20Z"`.
+pub fn format_rfc3339(timestamp: u64) -> String {
+    let days = timestamp / 86_400;
+    let secs_of_day = timestamp % 86_400;
+
+    // Civil-from-days (Howard Hinnant), shifted so eras start on March 1st
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        secs_of_day / 3_600,
+        secs_of_day % 3_600 / 60,
+        secs_of_day % 60
+    )
+}
+
+/// Encode `bytes` as standard, padded base64 (RFC 4648)
+pub fn encode_base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut encoded = String::with_capacity(bytes.chunks(3).len() * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (i, byte)| n | u32::from(*byte) << (16 - 8 * i));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+/// Truncate a string to at most `max_bytes` bytes without splitting a UTF-8 codepoint
+pub fn truncate_utf8(value: &str, max_bytes: usize) -> &str {
+    if value.len() <= max_bytes {
+        return value;
+    }
+    let mut end = max_bytes;
+    while !value.is_char_boundary(end) {
+        end -= 1;
+    }
+    &value[..end]
+}
+
+/// Label for the current thread: its name, or its id (e.g. `"ThreadId(7)"`) when unnamed
+pub fn current_thread_label() -> String {
+    let thread = std::thread::current();
+    match thread.name() {
+        Some(name) => name.to_string(),
+        None => format!("{:?}", thread.id()),
+    }
+}
+
+/// Number of threads in this process, where the platform reports it
+///
+/// Read from `/proc/self/status` on Linux; `None` elsewhere.
+pub fn process_thread_count() -> Option<usize> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    status
+        .lines()
+        .find_map(|line| line.strip_prefix("Threads:"))
+        .and_then(|count| count.trim().parse().ok())
+}
+
 /// Format a log level as a string
 pub fn format_level(level: LogLevel) -> &'static str {
     match level {
@@ -24,5 +109,32 @@ pub fn format_level(level: LogLevel) -> &'static str {
         LogLevel::Info => "INFO",
         LogLevel::Warn => "WARN",
         LogLevel::Error => "ERROR",
+        LogLevel::Fatal => "FATAL",
+    }
+}
+
+/// Format a log level as its first letter, e.g. `'W'` for `Warn`
+pub fn format_level_short(level: LogLevel) -> char {
+    match level {
+        LogLevel::Trace => 'T',
+        LogLevel::Debug => 'D',
+        LogLevel::Info => 'I',
+        LogLevel::Warn => 'W',
+        LogLevel::Error => 'E',
+        LogLevel::Fatal => 'F',
+    }
+}
+
+/// Format a log level with its numeric severity prefix, e.g. `"2_INFO"`
+///
+/// Unlike [`format_level`], these strings sort lexically in severity order.
+pub fn format_level_sortable(level: LogLevel) -> &'static str {
+    match level {
+        LogLevel::Trace => "0_TRACE",
+        LogLevel::Debug => "1_DEBUG",
+        LogLevel::Info => "2_INFO",
+        LogLevel::Warn => "3_WARN",
+        LogLevel::Error => "4_ERROR",
+        LogLevel::Fatal => "5_FATAL",
     }
 }